@@ -5,7 +5,24 @@
   max_width: 80,
   collapse_markup_spaces: false,
   reorder_import_items: true,
+  reorder_imports: false,
+  merge_imports: false,
   wrap_mode: "none",
+  trailing_comma: "always-when-multiline",
+  magic_trailing_comma: false,
+  align_assignments: false,
+  align_trailing_comments: false,
+  table_align: "none",
+  table_functions: (),
+  break_long_equations: false,
+  math_spacing: false,
+  symbol_style: "preserve",
+  inline_frac_style: "preserve",
+  block_frac_style: "preserve",
+  wrap_algorithm: "greedy",
+  cjk_break: "never",
+  sentence_locale: "en",
+  sentence_abbreviations: (),
 )
 
 #let parse(text) = {
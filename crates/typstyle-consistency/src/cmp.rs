@@ -11,7 +11,15 @@ use typst::{
 use typst_layout::{Page, PagedDocument};
 use typst_render::RenderOptions;
 
-use crate::{ErrorSink, image_diff::compute_diff_pixmap, sink_assert_eq, text_diff::CodeDiff};
+use crate::{
+    ErrorSink,
+    content_diff::ContentDivergence,
+    html::{compile_html, normalize_html},
+    image_diff::compute_diff_pixmap,
+    report::{PageReport, ReportEntry},
+    sink_assert_eq,
+    text_diff::CodeDiff,
+};
 
 /// Options for comparing formatted documents with original ones.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -22,6 +30,18 @@ pub struct CheckingOptions {
     pub expect_error: bool,
     /// Whether to enforce strict content equality.
     pub strict_content_equality: bool,
+    /// The export target whose compiled output is compared.
+    pub target: CompileTarget,
+}
+
+/// The export target used to compare compiled documents.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CompileTarget {
+    /// Compare the paged documents, rendering differing pages.
+    #[default]
+    Paged,
+    /// Compare the normalized DOM text of the HTML export.
+    Html,
 }
 
 /// Input for document comparison, consisting of a name and a world to compile.
@@ -44,6 +64,7 @@ fn compile_world(world: &dyn SourceWorld) -> Result<PagedDocument, EcoVec<Source
 
 /// Compare two documents for consistency, checking content equality first,
 /// then document/page equality, and finally rendering PNGs for any inconsistent pages.
+/// With [`CompileTarget::Html`], the normalized HTML exports are compared instead of pages.
 ///
 /// The checking order is optimized to report cheap content differences before
 /// compiling, while still compiling successful documents so metadata-only
@@ -57,9 +78,20 @@ pub fn compare_worlds(
     formatted: &ComparisonInput,
     options: CheckingOptions,
     err_sink: &mut ErrorSink,
+) -> Result<()> {
+    compare_worlds_with_report(original, formatted, options, err_sink, None)
+}
+
+/// Like [`compare_worlds`], but also records renders of differing pages into `report`.
+pub(crate) fn compare_worlds_with_report(
+    original: &ComparisonInput,
+    formatted: &ComparisonInput,
+    options: CheckingOptions,
+    err_sink: &mut ErrorSink,
+    report: Option<&mut ReportEntry>,
 ) -> Result<()> {
     let mut sub_sink = ErrorSink::new(format!("comparing with `{}`", formatted.name));
-    compare_worlds_impl(original, formatted, options, &mut sub_sink)?;
+    compare_worlds_impl(original, formatted, options, &mut sub_sink, report)?;
     sub_sink.sink_to(err_sink);
     Ok(())
 }
@@ -69,6 +101,7 @@ fn compare_worlds_impl(
     formatted: &ComparisonInput,
     options: CheckingOptions,
     sink: &mut ErrorSink,
+    report: Option<&mut ReportEntry>,
 ) -> Result<()> {
     // Step 1: Check content equality (cheap evaluation without full compilation)
     let original_content = eval_content(original.world);
@@ -95,6 +128,9 @@ fn compare_worlds_impl(
                 if orig_repr == fmt_repr {
                     sink.push("However, their representations are identical.");
                 }
+                if let Some(divergence) = ContentDivergence::find(orig, fmt) {
+                    sink.push(divergence.describe(original.world, formatted.world));
+                }
             }
         }
         (Err(orig_err), Err(fmt_err)) => {
@@ -119,17 +155,59 @@ fn compare_worlds_impl(
     }
 
     // Step 2: Compile documents to catch metadata and rendered-output changes.
-    let original_result = compile_world(original.world);
-    let formatted_result = compile_world(formatted.world);
+    match options.target {
+        CompileTarget::Paged => check_compile_results(
+            original,
+            formatted,
+            &compile_world(original.world),
+            &compile_world(formatted.world),
+            options,
+            sink,
+            |orig_doc, fmt_doc, sink| {
+                // Both compiled successfully, check page equality
+                check_pages_equal(
+                    orig_doc,
+                    fmt_doc,
+                    &original.name,
+                    &formatted.name,
+                    sink,
+                    report,
+                )
+            },
+        ),
+        CompileTarget::Html => check_compile_results(
+            original,
+            formatted,
+            &compile_html(original.world.as_world()),
+            &compile_html(formatted.world.as_world()),
+            options,
+            sink,
+            |orig_html, fmt_html, sink| {
+                check_html_equal(orig_html, fmt_html, sink);
+                Ok(())
+            },
+        ),
+    }
+}
 
-    match (&original_result, &formatted_result) {
+/// Check the compilation results of both documents, comparing them with `check_equal`
+/// if both compiled successfully.
+fn check_compile_results<T>(
+    original: &ComparisonInput,
+    formatted: &ComparisonInput,
+    original_result: &Result<T, EcoVec<SourceDiagnostic>>,
+    formatted_result: &Result<T, EcoVec<SourceDiagnostic>>,
+    options: CheckingOptions,
+    sink: &mut ErrorSink,
+    check_equal: impl FnOnce(&T, &T, &mut ErrorSink) -> Result<()>,
+) -> Result<()> {
+    match (original_result, formatted_result) {
         (Ok(orig_doc), Ok(fmt_doc)) => {
             if options.expect_error {
                 sink.push("Both docs compiled successfully, but were expected to fail.");
                 return Ok(());
             }
-            // Both compiled successfully, check page equality
-            check_pages_equal(orig_doc, fmt_doc, &original.name, &formatted.name, sink)?;
+            check_equal(orig_doc, fmt_doc, sink)?;
         }
         (Err(orig_err), Err(fmt_err)) => {
             if options.expect_compile_success {
@@ -154,6 +232,18 @@ fn compare_worlds_impl(
     Ok(())
 }
 
+/// Check that the HTML exports of two documents have the same normalized DOM text.
+fn check_html_equal(original: &str, formatted: &str, sink: &mut ErrorSink) {
+    let original = normalize_html(original);
+    let formatted = normalize_html(formatted);
+    if original != formatted {
+        let diff = CodeDiff::new(&original, &formatted);
+        sink.push(format!(
+            "HTML output differs between original and formatted documents:\n{diff}"
+        ));
+    }
+}
+
 /// Check that two error lists are consistent (same count and messages).
 fn check_error_consistency(
     original_errors: &[SourceDiagnostic],
@@ -219,6 +309,7 @@ fn check_pages_equal(
     original_name: &str,
     formatted_name: &str,
     sink: &mut ErrorSink,
+    report: Option<&mut ReportEntry>,
 ) -> Result<()> {
     // Check document metadata
     check_doc_meta(original, formatted, sink);
@@ -267,6 +358,7 @@ fn check_pages_equal(
         formatted_name,
         &pages_with_hash_diff,
         sink,
+        report,
     )?;
 
     Ok(())
@@ -346,6 +438,7 @@ fn render_diff_pages(
     formatted_name: &str,
     pages_with_diff: &[usize],
     sink: &mut ErrorSink,
+    mut report: Option<&mut ReportEntry>,
 ) -> Result<()> {
     let total_pages = original.len();
 
@@ -405,10 +498,17 @@ fn render_diff_pages(
         let mut msg =
             format!("Page {page_num}/{total_pages} differs by {diff_pixel_count} pixels.");
 
-        // PNGs differ - save them if environment variable is set
-        if let Some(save_path) = save_dir.as_ref() {
-            let diff_pixmap = compute_diff_pixmap(&orig_png, &fmt_png);
+        // The diff image is only computed if it is reported or saved.
+        let diff_pixmap = (report.is_some() || save_dir.is_some())
+            .then(|| compute_diff_pixmap(&orig_png, &fmt_png));
+        if let (Some(report), Some(diff_pixmap)) = (report.as_deref_mut(), &diff_pixmap) {
+            report
+                .pages
+                .push(PageReport::new(page_num, &orig_png, &fmt_png, diff_pixmap)?);
+        }
 
+        // PNGs differ - save them if environment variable is set
+        if let (Some(save_path), Some(diff_pixmap)) = (save_dir.as_ref(), &diff_pixmap) {
             let orig_filename = format!("{}_{page_num}.png", sanitize_filename(original_name));
             let fmt_filename = format!("{}_{page_num}.png", sanitize_filename(formatted_name));
             let diff_filename = format!("{}_{page_num}_diff.png", sanitize_filename(original_name));
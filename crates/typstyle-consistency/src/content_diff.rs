@@ -0,0 +1,268 @@
+use std::fmt::Write;
+
+use tinymist_world::SourceWorld;
+use typst::{
+    foundations::{Content, Repr, Value},
+    syntax::Span,
+};
+
+/// The first diverging element found by walking two content trees in parallel.
+pub(crate) struct ContentDivergence {
+    /// The path from the root to the diverging element, such as `sequence.children[2]`.
+    path: String,
+    /// Why the elements diverge.
+    reason: String,
+    original: Content,
+    formatted: Content,
+    /// The nearest spans attached to the original element or its ancestors.
+    original_span: Span,
+    /// The nearest spans attached to the formatted element or its ancestors.
+    formatted_span: Span,
+}
+
+impl ContentDivergence {
+    /// Walks both trees in parallel and returns the first element where they diverge.
+    pub fn find(original: &Content, formatted: &Content) -> Option<Self> {
+        let mut walker = Walker {
+            path: String::new(),
+            spans: (Span::detached(), Span::detached()),
+        };
+        walker.diff_content(original, formatted)
+    }
+
+    /// Describes the divergence, locating the originating syntax nodes in both worlds.
+    pub fn describe(&self, original: &dyn SourceWorld, formatted: &dyn SourceWorld) -> String {
+        let path = if self.path.is_empty() {
+            "<root>"
+        } else {
+            &self.path
+        };
+        format!(
+            "First divergence at `{path}`: {}\n  original:  {}\n    at {}\n  formatted: {}\n    at {}",
+            self.reason,
+            describe_element(&self.original),
+            describe_span(original, self.original_span),
+            describe_element(&self.formatted),
+            describe_span(formatted, self.formatted_span),
+        )
+    }
+}
+
+struct Walker {
+    path: String,
+    /// The nearest non-detached spans of the current elements.
+    spans: (Span, Span),
+}
+
+impl Walker {
+    fn diff_content(
+        &mut self,
+        original: &Content,
+        formatted: &Content,
+    ) -> Option<ContentDivergence> {
+        if original == formatted {
+            return None;
+        }
+
+        let saved_spans = self.spans;
+        if !original.span().is_detached() {
+            self.spans.0 = original.span();
+        }
+        if !formatted.span().is_detached() {
+            self.spans.1 = formatted.span();
+        }
+        let saved_len = self.path.len();
+        if !self.path.is_empty() {
+            self.path.push('.');
+        }
+        self.path.push_str(original.elem().name());
+
+        let divergence = if original.elem() != formatted.elem() {
+            Some(self.diverge(
+                original,
+                formatted,
+                format!(
+                    "the element kinds differ (`{}` vs `{}`)",
+                    original.elem().name(),
+                    formatted.elem().name()
+                ),
+            ))
+        } else {
+            self.diff_fields(original, formatted)
+        };
+
+        self.path.truncate(saved_len);
+        self.spans = saved_spans;
+        divergence
+    }
+
+    fn diff_fields(
+        &mut self,
+        original: &Content,
+        formatted: &Content,
+    ) -> Option<ContentDivergence> {
+        let original_fields = original.fields();
+        let formatted_fields = formatted.fields();
+        for (key, original_value) in original_fields.iter() {
+            let Some(formatted_value) = formatted_fields.get(key).ok() else {
+                return Some(self.diverge(
+                    original,
+                    formatted,
+                    format!("the field `{key}` is missing in the formatted element"),
+                ));
+            };
+            if original_value == formatted_value {
+                continue;
+            }
+
+            let saved_len = self.path.len();
+            let _ = write!(self.path, ".{key}");
+            let divergence = match (original_value, formatted_value) {
+                (Value::Content(orig), Value::Content(fmt)) => self.diff_content(orig, fmt),
+                // A differing item in the common prefix is reported before the lengths, as it is
+                // more likely to be the cause.
+                (Value::Array(orig), Value::Array(fmt)) => orig
+                    .iter()
+                    .zip(fmt.iter())
+                    .enumerate()
+                    .find_map(|(i, items)| self.diff_item(original, formatted, i, items))
+                    .or_else(|| {
+                        let common = orig.len().min(fmt.len());
+                        let (side, extra) = if orig.len() > common {
+                            ("original", orig.iter().nth(common)?)
+                        } else {
+                            ("formatted", fmt.iter().nth(common)?)
+                        };
+                        Some(self.diverge(
+                            original,
+                            formatted,
+                            format!(
+                                "the field `{key}` has {} items in the original and {} in the \
+                                 formatted, and the first extra item in the {side} is `{}` at \
+                                 index {common}",
+                                orig.len(),
+                                fmt.len(),
+                                truncate(&extra.repr(), 60),
+                            ),
+                        ))
+                    }),
+                _ => Some(self.diverge(
+                    original,
+                    formatted,
+                    format!(
+                        "the field `{key}` differs (`{}` vs `{}`)",
+                        original_value.repr(),
+                        formatted_value.repr()
+                    ),
+                )),
+            };
+            self.path.truncate(saved_len);
+            if divergence.is_some() {
+                return divergence;
+            }
+        }
+
+        for (key, _) in formatted_fields.iter() {
+            if original_fields.get(key).is_err() {
+                return Some(self.diverge(
+                    original,
+                    formatted,
+                    format!("the field `{key}` is missing in the original element"),
+                ));
+            }
+        }
+
+        // The trees are unequal, but no field differs, e.g., due to hidden state.
+        Some(self.diverge(original, formatted, "the elements differ".to_string()))
+    }
+
+    fn diff_item(
+        &mut self,
+        original: &Content,
+        formatted: &Content,
+        index: usize,
+        items: (&Value, &Value),
+    ) -> Option<ContentDivergence> {
+        let saved_len = self.path.len();
+        let _ = write!(self.path, "[{index}]");
+        let divergence = match items {
+            (Value::Content(orig), Value::Content(fmt)) => self.diff_content(orig, fmt),
+            (orig, fmt) if orig == fmt => None,
+            (orig, fmt) => Some(self.diverge(
+                original,
+                formatted,
+                format!("the item differs (`{}` vs `{}`)", orig.repr(), fmt.repr()),
+            )),
+        };
+        self.path.truncate(saved_len);
+        divergence
+    }
+
+    fn diverge(
+        &self,
+        original: &Content,
+        formatted: &Content,
+        reason: String,
+    ) -> ContentDivergence {
+        ContentDivergence {
+            path: self.path.clone(),
+            reason,
+            original: original.clone(),
+            formatted: formatted.clone(),
+            original_span: self.spans.0,
+            formatted_span: self.spans.1,
+        }
+    }
+}
+
+/// Describes an element by its kind and its non-content fields.
+fn describe_element(content: &Content) -> String {
+    let fields = content
+        .fields()
+        .iter()
+        .filter(|(_, value)| !matches!(value, Value::Content(_) | Value::Array(_)))
+        .map(|(key, value)| format!("{key}: {}", truncate(&value.repr(), 60)))
+        .collect::<Vec<_>>();
+    format!("{}({})", content.elem().name(), fields.join(", "))
+}
+
+/// Locates a span as `path:line:column`, followed by the kind and text of its syntax node.
+fn describe_span(world: &dyn SourceWorld, span: Span) -> String {
+    let Some(id) = span.id() else {
+        return "<detached>".to_string();
+    };
+    let Ok(source) = world.source(id) else {
+        return format!("{id:?}");
+    };
+    let path = world
+        .path_for_id(id)
+        .map(|path| path.as_path().display().to_string())
+        .unwrap_or_else(|_| format!("{id:?}"));
+    let Some(range) = source.range(span) else {
+        return path;
+    };
+    let line = source
+        .lines()
+        .byte_to_line(range.start)
+        .map_or(0, |line| line + 1);
+    let column = source
+        .lines()
+        .byte_to_column(range.start)
+        .map_or(0, |column| column + 1);
+    let kind = source
+        .find(span)
+        .map(|node| format!("{:?}", node.kind()))
+        .unwrap_or_default();
+    let text = source.text()[range].lines().next().unwrap_or_default();
+    format!("{path}:{line}:{column} ({kind} `{}`)", truncate(text, 40))
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        text.to_string()
+    } else {
+        let mut truncated = text.chars().take(max_chars).collect::<String>();
+        truncated.push('…');
+        truncated
+    }
+}
@@ -22,6 +22,15 @@ impl ErrorSink {
         self.errors.is_empty()
     }
 
+    /// Returns the number of errors directly pushed to this sink.
+    pub(crate) fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub(crate) fn errors(&self) -> &[String] {
+        &self.errors
+    }
+
     pub fn sink_to(&self, parent: &mut Self) {
         if !self.errors.is_empty() {
             parent.push(format!("{self}"));
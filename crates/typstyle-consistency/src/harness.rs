@@ -19,7 +19,8 @@ use typst::{
 use walkdir::WalkDir;
 
 use crate::{
-    CheckingOptions, ComparisonInput, ErrorSink, FormattedWorld, SourceMap, compare_worlds,
+    CheckingOptions, ComparisonInput, ErrorSink, FormattedWorld, HtmlReport, SourceMap,
+    cmp::compare_worlds_with_report, compare_worlds, report::ReportEntry,
 };
 
 pub struct FormattedSources {
@@ -27,6 +28,17 @@ pub struct FormattedSources {
     pub name: String,
     /// Map of file IDs to their formatted sources.
     pub sources: SourceMap,
+    /// The formatter configuration, shown in HTML reports. May be empty.
+    pub config: String,
+}
+
+/// Options for the compilation universe of a [`FormatterHarness`].
+#[derive(Debug, Default, Clone)]
+pub struct HarnessOptions {
+    /// Additional directories to search for fonts.
+    pub font_paths: Vec<PathBuf>,
+    /// Whether to ignore fonts installed on the system.
+    pub no_system_fonts: bool,
 }
 
 pub struct FormatterHarness {
@@ -38,6 +50,8 @@ pub struct FormatterHarness {
     formattable: FxHashSet<FileId>,
     /// The universe used for compiling.
     verse: TypstSystemUniverse,
+    /// The HTML report collecting failing comparisons, if enabled.
+    report: Option<HtmlReport>,
 }
 
 impl FormatterHarness {
@@ -46,18 +60,45 @@ impl FormatterHarness {
     }
 
     pub fn new(name: String, project_root: PathBuf) -> Result<Self> {
+        Self::with_options(name, project_root, Default::default())
+    }
+
+    pub fn with_options(
+        name: String,
+        project_root: PathBuf,
+        options: HarnessOptions,
+    ) -> Result<Self> {
         Ok(Self {
             name,
             project_root,
             formattable: Default::default(),
             verse: TypstSystemUniverse::new(CompileOpts {
                 entry: EntryOpts::new_workspace(Self::vroot().to_path_buf()),
+                font_paths: options.font_paths,
+                no_system_fonts: options.no_system_fonts,
                 with_embedded_fonts: typst_assets::fonts().map(Cow::Borrowed).collect(),
                 ..Default::default()
             })?,
+            report: None,
         })
     }
 
+    /// Collect failing comparisons into an HTML report, available via [`Self::html_report`].
+    pub fn enable_html_report(&mut self) -> &mut Self {
+        self.report.get_or_insert_with(Default::default);
+        self
+    }
+
+    /// The HTML report of failing comparisons, if enabled.
+    pub fn html_report(&self) -> Option<&HtmlReport> {
+        self.report.as_ref()
+    }
+
+    /// Returns the number of formattable sources in the workspace.
+    pub fn formattable_count(&self) -> usize {
+        self.formattable.len()
+    }
+
     /// Add all files in the given directory to the workspace.
     pub fn add_all_files(
         &mut self,
@@ -210,7 +251,40 @@ impl FormatterHarness {
                 world: &world,
             };
 
-            compare_worlds(&original, &formatted, options, &mut sub_sink)?;
+            let Some(report) = self.report.as_mut() else {
+                compare_worlds(&original, &formatted, options, &mut sub_sink)?;
+                continue;
+            };
+
+            let mut entry = ReportEntry {
+                name: formatted.name.clone(),
+                config: sources.config.clone(),
+                ..Default::default()
+            };
+            let error_count = sub_sink.len();
+            compare_worlds_with_report(
+                &original,
+                &formatted,
+                options,
+                &mut sub_sink,
+                Some(&mut entry),
+            )?;
+            if sub_sink.len() == error_count {
+                continue;
+            }
+
+            entry.messages = sub_sink.errors()[error_count..].to_vec();
+            for (&fid, source) in &sources.sources {
+                let original_text = base_world.source(fid)?.text().to_string();
+                if original_text != source.text() {
+                    let path = self.verse.path_for_id(fid)?.as_path().display().to_string();
+                    entry
+                        .sources
+                        .push((path, original_text, source.text().to_string()));
+                }
+            }
+            entry.sources.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+            report.push(entry);
         }
 
         sub_sink.sink_to(err_sink);
@@ -0,0 +1,123 @@
+use typst::{
+    Feature, Library, LibraryExt, World,
+    diag::{FileResult, SourceDiagnostic},
+    ecow::EcoVec,
+    foundations::{Bytes, Datetime, Duration},
+    syntax::{FileId, Source},
+    text::{Font, FontBook},
+    utils::LazyHash,
+};
+use typst_html::HtmlDocument;
+
+/// A world that delegates to another one, but with HTML export enabled in its library.
+struct HtmlWorld<'a> {
+    base: &'a dyn World,
+    library: LazyHash<Library>,
+}
+
+impl World for HtmlWorld<'_> {
+    fn library(&self) -> &LazyHash<Library> {
+        &self.library
+    }
+
+    fn book(&self) -> &LazyHash<FontBook> {
+        self.base.book()
+    }
+
+    fn main(&self) -> FileId {
+        self.base.main()
+    }
+
+    fn source(&self, id: FileId) -> FileResult<Source> {
+        self.base.source(id)
+    }
+
+    fn file(&self, id: FileId) -> FileResult<Bytes> {
+        self.base.file(id)
+    }
+
+    fn font(&self, index: usize) -> Option<Font> {
+        self.base.font(index)
+    }
+
+    fn today(&self, offset: Option<Duration>) -> Option<Datetime> {
+        self.base.today(offset)
+    }
+}
+
+/// Compile a world into an HTML string.
+pub(crate) fn compile_html(world: &dyn World) -> Result<String, EcoVec<SourceDiagnostic>> {
+    let world = HtmlWorld {
+        base: world,
+        library: LazyHash::new(
+            Library::builder()
+                .with_features([Feature::Html].into_iter().collect())
+                .build(),
+        ),
+    };
+    let document = typst::compile::<HtmlDocument>(&world).output?;
+    typst_html::html(&document)
+}
+
+/// Normalize HTML into one tag or text run per line, so that only differences visible in the
+/// DOM remain.
+///
+/// Runs of whitespace in text collapse to a single space, as browsers render them.
+/// Whitespace-only text containing a linebreak is indentation inserted by the HTML writer
+/// between block elements, so it is dropped. Text in `<pre>`, `<textarea>`, `<script>` and
+/// `<style>` is kept verbatim.
+pub(crate) fn normalize_html(html: &str) -> String {
+    let mut normalized = String::new();
+    let mut preformatted = 0usize;
+    let mut rest = html;
+    while !rest.is_empty() {
+        let (token, tail) = if rest.starts_with('<') {
+            let end = rest.find('>').map_or(rest.len(), |i| i + 1);
+            rest.split_at(end)
+        } else {
+            rest.split_at(rest.find('<').unwrap_or(rest.len()))
+        };
+        rest = tail;
+
+        if token.starts_with('<') {
+            let name = token
+                .trim_start_matches(['<', '/'])
+                .split(|c: char| c.is_whitespace() || c == '>' || c == '/')
+                .next()
+                .unwrap_or_default()
+                .to_ascii_lowercase();
+            if matches!(name.as_str(), "pre" | "textarea" | "script" | "style") {
+                if token.starts_with("</") {
+                    preformatted = preformatted.saturating_sub(1);
+                } else {
+                    preformatted += 1;
+                }
+            }
+            normalized.push_str(token);
+        } else if preformatted > 0 {
+            normalized.push_str(&token.replace('\n', "\\n"));
+        } else if token.trim().is_empty() {
+            if token.contains('\n') {
+                continue;
+            }
+            normalized.push(' ');
+        } else {
+            let mut collapsed = String::with_capacity(token.len());
+            let mut in_space = false;
+            for c in token.chars() {
+                if c.is_whitespace() {
+                    if !in_space {
+                        collapsed.push(' ');
+                    }
+                    in_space = true;
+                } else {
+                    collapsed.push(c);
+                    in_space = false;
+                }
+            }
+            normalized.push_str(&collapsed);
+        }
+        normalized.push('\n');
+    }
+    normalized
+}
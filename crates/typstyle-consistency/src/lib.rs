@@ -1,11 +1,15 @@
 mod cmp;
+mod content_diff;
 mod err;
 mod harness;
+mod html;
 mod image_diff;
+mod report;
 mod text_diff;
 mod world;
 
-pub use cmp::{CheckingOptions, ComparisonInput, compare_worlds};
+pub use cmp::{CheckingOptions, ComparisonInput, CompileTarget, compare_worlds};
 pub use err::ErrorSink;
-pub use harness::{FormattedSources, FormatterHarness};
+pub use harness::{FormattedSources, FormatterHarness, HarnessOptions};
+pub use report::HtmlReport;
 pub use world::{FormattedWorld, SourceMap};
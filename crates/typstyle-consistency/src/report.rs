@@ -0,0 +1,217 @@
+use std::{fmt::Write, path::Path};
+
+use anyhow::Result;
+use base64::prelude::*;
+use tiny_skia::Pixmap;
+
+use crate::text_diff::CodeDiff;
+
+/// A self-contained HTML report of failing consistency checks.
+///
+/// All renders are embedded as data URIs, so the report can be shared as a single file.
+#[derive(Default)]
+pub struct HtmlReport {
+    entries: Vec<ReportEntry>,
+}
+
+/// A failing comparison between the original and one formatted world.
+#[derive(Default)]
+pub(crate) struct ReportEntry {
+    /// The name of the comparison, including the entry point and formatter configuration.
+    pub name: String,
+    /// The formatter configuration that was used.
+    pub config: String,
+    /// The errors reported by the comparison.
+    pub messages: Vec<String>,
+    /// The changed sources, as `(path, original, formatted)`.
+    pub sources: Vec<(String, String, String)>,
+    /// The pages whose renders differ.
+    pub pages: Vec<PageReport>,
+}
+
+/// PNG renders of a differing page.
+pub(crate) struct PageReport {
+    /// The 1-based page number.
+    pub number: usize,
+    pub original: Vec<u8>,
+    pub formatted: Vec<u8>,
+    pub diff: Vec<u8>,
+}
+
+impl PageReport {
+    pub fn new(
+        number: usize,
+        original: &Pixmap,
+        formatted: &Pixmap,
+        diff: &Pixmap,
+    ) -> Result<Self> {
+        Ok(Self {
+            number,
+            original: original.encode_png()?,
+            formatted: formatted.encode_png()?,
+            diff: diff.encode_png()?,
+        })
+    }
+}
+
+impl HtmlReport {
+    pub(crate) fn push(&mut self, entry: ReportEntry) {
+        self.entries.push(entry);
+    }
+
+    /// Returns `true` if no failing comparison has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Writes the report to the given path as a single HTML file.
+    pub fn write(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, self.render())?;
+        Ok(())
+    }
+
+    /// Renders the report as a self-contained HTML document.
+    pub fn render(&self) -> String {
+        let mut html = String::new();
+        html.push_str(concat!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n",
+            "<title>Typstyle consistency report</title>\n<style>\n",
+            "body { font-family: sans-serif; margin: 2em; }\n",
+            "pre { background: #f6f8fa; padding: 0.5em; overflow-x: auto; }\n",
+            ".del { background: #ffebe9; color: #82071e; }\n",
+            ".ins { background: #dafbe1; color: #116329; }\n",
+            ".hunk { color: #8250df; }\n",
+            ".pages { display: flex; gap: 1em; flex-wrap: wrap; }\n",
+            "figure { margin: 0; }\n",
+            "figure img { max-width: 32vw; border: 1px solid #d0d7de; }\n",
+            ".overlay { position: relative; }\n",
+            ".overlay img + img { position: absolute; left: 0; top: 0; opacity: 0.8; }\n",
+            "</style>\n</head>\n<body>\n<h1>Typstyle consistency report</h1>\n",
+        ));
+        if self.entries.is_empty() {
+            html.push_str("<p>No failures.</p>\n");
+        }
+        for entry in &self.entries {
+            entry.render(&mut html);
+        }
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+}
+
+impl ReportEntry {
+    fn render(&self, html: &mut String) {
+        let _ = writeln!(html, "<section>\n<h2>{}</h2>", escape_html(&self.name));
+        if !self.config.is_empty() {
+            let _ = writeln!(
+                html,
+                "<details>\n<summary>Configuration</summary>\n<pre>{}</pre>\n</details>",
+                escape_html(&self.config)
+            );
+        }
+        for message in &self.messages {
+            let _ = writeln!(html, "<pre>{}</pre>", escape_html(&strip_ansi(message)));
+        }
+        for (path, original, formatted) in &self.sources {
+            let _ = writeln!(
+                html,
+                "<h3>Source diff of <code>{}</code></h3>",
+                escape_html(path)
+            );
+            let _ = writeln!(
+                html,
+                "<pre>{}</pre>",
+                CodeDiff::new(original, formatted).to_html()
+            );
+        }
+        for page in &self.pages {
+            let _ = writeln!(html, "<h3>Page {}</h3>\n<div class=\"pages\">", page.number);
+            write_figure(html, "Original", &[&page.original]);
+            write_figure(html, "Formatted", &[&page.formatted]);
+            write_figure(html, "Diff overlay", &[&page.original, &page.diff]);
+            html.push_str("</div>\n");
+        }
+        html.push_str("</section>\n");
+    }
+}
+
+/// Writes a figure of stacked PNG images, where later images are laid over the first one.
+fn write_figure(html: &mut String, caption: &str, pngs: &[&[u8]]) {
+    html.push_str("<figure>\n<div class=\"overlay\">");
+    for png in pngs {
+        let _ = write!(
+            html,
+            "<img src=\"data:image/png;base64,{}\">",
+            BASE64_STANDARD.encode(png)
+        );
+    }
+    let _ = writeln!(
+        html,
+        "</div>\n<figcaption>{caption}</figcaption>\n</figure>"
+    );
+}
+
+pub(crate) fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Removes ANSI color sequences, which colored diffs in error messages may contain.
+fn strip_ansi(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Skip `ESC [ ... <final byte>`.
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            stripped.push(c);
+        }
+    }
+    stripped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(strip_ansi("\x1b[31m-a\x1b[0m b"), "-a b");
+    }
+
+    #[test]
+    fn test_render_source_diff() {
+        let mut report = HtmlReport::default();
+        report.push(ReportEntry {
+            name: "main.typ - <default>".to_string(),
+            sources: vec![(
+                "main.typ".to_string(),
+                "#let a  =  1\n".to_string(),
+                "#let a = 1\n".to_string(),
+            )],
+            ..Default::default()
+        });
+
+        let html = report.render();
+        assert!(html.contains("<h2>main.typ - &lt;default&gt;</h2>"));
+        assert!(html.contains("<span class=\"del\">-#let a  =  1\n</span>"));
+        assert!(html.contains("<span class=\"ins\">+#let a = 1\n</span>"));
+    }
+}
@@ -6,6 +6,8 @@ use std::borrow::Cow;
 use colored::Colorize;
 use similar::{ChangeTag, TextDiff};
 
+use crate::report::escape_html;
+
 pub struct CodeDiff<'a> {
     diff: TextDiff<'a, 'a, str>,
     header: Option<(&'a str, &'a str)>,
@@ -23,6 +25,37 @@ impl<'a> CodeDiff<'a> {
     }
 }
 
+impl CodeDiff<'_> {
+    /// Renders the unified diff as escaped HTML, with changed lines wrapped in styled spans.
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        let mut unified = self.diff.unified_diff();
+        unified.missing_newline_hint(false);
+
+        for hunk in unified.iter_hunks() {
+            html.push_str(&format!("<span class=\"hunk\">{}</span>\n", hunk.header()));
+
+            for change in hunk.iter_changes() {
+                let mut value = escape_html(change.value());
+                if !self.diff.newline_terminated() || change.missing_newline() {
+                    value.push('\n');
+                }
+                match change.tag() {
+                    ChangeTag::Equal => html.push_str(&format!(" {value}")),
+                    ChangeTag::Delete => {
+                        html.push_str(&format!("<span class=\"del\">-{value}</span>"))
+                    }
+                    ChangeTag::Insert => {
+                        html.push_str(&format!("<span class=\"ins\">+{value}</span>"))
+                    }
+                }
+            }
+        }
+
+        html
+    }
+}
+
 impl std::fmt::Display for CodeDiff<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Some((original, modified)) = self.header {
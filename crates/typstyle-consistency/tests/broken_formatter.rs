@@ -1,7 +1,9 @@
 use std::path::{Path, PathBuf};
 
 use anyhow::{Result, bail};
-use typstyle_consistency::{CheckingOptions, ErrorSink, FormattedSources, FormatterHarness};
+use typstyle_consistency::{
+    CheckingOptions, CompileTarget, ErrorSink, FormattedSources, FormatterHarness,
+};
 
 fn compare_with_formatter(
     source: &str,
@@ -25,6 +27,7 @@ fn compare_with_formatter_fn(
     let formatted = FormattedSources {
         name: "broken".to_string(),
         sources: harness.format(&base_world, formatter, &mut sink)?,
+        config: String::new(),
     };
 
     harness.compile_and_compare([formatted].iter(), main_path, options, &mut sink)?;
@@ -134,6 +137,48 @@ fn detects_content_changes_when_strict_content_equality_is_enabled() -> Result<(
     Ok(())
 }
 
+#[test]
+fn locates_first_content_divergence() -> Result<()> {
+    let sink = compare_with_formatter(
+        "= Title\n\nHello *world*\n",
+        "= Title\n\nHello *word*\n",
+        CheckingOptions {
+            strict_content_equality: true,
+            ..Default::default()
+        },
+    )?;
+
+    let output = assert_detected(sink, "First divergence at");
+    assert!(
+        output.contains("strong.body") && output.contains("text: \"world\""),
+        "divergence did not name the changed element:\n{output}"
+    );
+    assert!(
+        output.contains("main.typ:3:8"),
+        "divergence did not locate the changed source:\n{output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn locates_changed_child_before_length_mismatch() -> Result<()> {
+    let sink = compare_with_formatter(
+        "Hello *world* again\n",
+        "Hello *word*\n",
+        CheckingOptions {
+            strict_content_equality: true,
+            ..Default::default()
+        },
+    )?;
+
+    let output = assert_detected(sink, "First divergence at");
+    assert!(
+        output.contains("strong.body") && !output.contains("items in the original"),
+        "divergence did not name the changed child:\n{output}"
+    );
+    Ok(())
+}
+
 #[test]
 fn detects_rendered_page_changes() -> Result<()> {
     let sink = compare_with_formatter("Hello\n", "World\n", Default::default())?;
@@ -142,6 +187,65 @@ fn detects_rendered_page_changes() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn reports_rendered_page_changes_in_html() -> Result<()> {
+    let main_path = Path::new("main.typ");
+    let mut sink = ErrorSink::new("html report test".to_string());
+    let mut harness = FormatterHarness::new("html report".to_string(), PathBuf::new())?;
+    harness.add_source_file(main_path, "Hello\n")?;
+    harness.enable_html_report();
+
+    let base_world = harness.snapshot();
+    let formatted = FormattedSources {
+        name: "broken".to_string(),
+        sources: harness.format(&base_world, |_| Ok("World\n".to_string()), &mut sink)?,
+        config: "Config { max_width: 80 }".to_string(),
+    };
+    harness.compile_and_compare([formatted].iter(), main_path, Default::default(), &mut sink)?;
+
+    assert_detected(sink, "Page 1/1 differs by");
+    let html = harness.html_report().unwrap().render();
+    assert!(html.contains("Config { max_width: 80 }"));
+    assert!(html.contains("<span class=\"del\">-Hello\n</span>"));
+    assert!(html.contains("<span class=\"ins\">+World\n</span>"));
+    assert!(html.contains("<h3>Page 1</h3>"));
+    assert!(html.contains("data:image/png;base64,"));
+    Ok(())
+}
+
+#[test]
+fn accepts_unchanged_html_output() -> Result<()> {
+    let sink = compare_with_formatter(
+        "= Title\nHello  *world*\n",
+        "= Title\nHello *world*\n",
+        CheckingOptions {
+            target: CompileTarget::Html,
+            ..Default::default()
+        },
+    )?;
+
+    assert_ok(sink);
+    Ok(())
+}
+
+#[test]
+fn detects_html_output_changes() -> Result<()> {
+    let sink = compare_with_formatter(
+        "Hello\n",
+        "World\n",
+        CheckingOptions {
+            target: CompileTarget::Html,
+            ..Default::default()
+        },
+    )?;
+
+    assert_detected(
+        sink,
+        "HTML output differs between original and formatted documents",
+    );
+    Ok(())
+}
+
 #[test]
 fn detects_page_count_changes() -> Result<()> {
     let sink = compare_with_formatter(
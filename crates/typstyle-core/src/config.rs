@@ -13,8 +13,50 @@ pub struct Config {
     pub collapse_markup_spaces: bool,
     /// When `true`, import items are sorted alphabetically.
     pub reorder_import_items: bool,
+    /// When `true`, consecutive import statements in markup are sorted by their sources.
+    pub reorder_imports: bool,
+    /// When `true`, consecutive imports of items from the same source in markup are merged.
+    pub merge_imports: bool,
     /// Text wrapping mode for markup.
     pub wrap_mode: WrapMode,
+    /// When to add trailing commas to lists, args, params, dicts, destructuring and imports.
+    pub trailing_comma: TrailingComma,
+    /// When `true`, lists ending with a trailing comma in the source are always expanded,
+    /// and other lists are folded if they fit, regardless of linebreaks in the source.
+    pub magic_trailing_comma: bool,
+    /// When `true`, `=` is aligned across consecutive single-line `let` bindings,
+    /// and values are aligned across named args and dict entries laid out one per line.
+    pub align_assignments: bool,
+    /// When `true`, trailing line comments on consecutive lines are aligned in one column.
+    pub align_trailing_comments: bool,
+    /// How to align cells within the columns of tables and grids.
+    pub table_align: TableAlign,
+    /// Additional functions formatted like `table` and `grid`, given as names or dotted paths
+    /// such as `my-table` or `tablex.tablex`. A path may be followed by `:` and the name of the
+    /// argument that gives the number of columns, which defaults to `columns`.
+    pub table_functions: Vec<String>,
+    /// When `true`, spaces in math are normalized around binary and relation operators, before
+    /// primes, and inside delimiters.
+    pub math_spacing: bool,
+    /// When `true`, block equations that exceed the line width are broken before top-level
+    /// relation operators and then before `+` and `-`.
+    pub break_long_equations: bool,
+    /// How to spell symbols that have both a shorthand and a name, such as `->` and `arrow.r`.
+    pub symbol_style: SymbolStyle,
+    /// How to write fractions in inline equations.
+    pub inline_frac_style: FracStyle,
+    /// How to write fractions in block equations.
+    pub block_frac_style: FracStyle,
+    /// How to choose line breaks when filling markup with [`WrapMode::Fill`].
+    pub wrap_algorithm: WrapAlgorithm,
+    /// Whether text may be wrapped between CJK characters.
+    pub cjk_break: CjkBreak,
+    /// The language of markup text, such as `en` or `de`. It chooses the built-in abbreviations
+    /// whose period does not end a sentence in sentence and clause wrap modes.
+    pub sentence_locale: String,
+    /// Additional abbreviations, such as `Thm.` or `Lem.`, whose period does not end a sentence.
+    /// A period after a single capital initial never ends a sentence either.
+    pub sentence_abbreviations: Vec<String>,
 }
 
 /// Text wrapping mode for markup.
@@ -29,6 +71,110 @@ pub enum WrapMode {
     Fill,
     /// Place each sentence on its own line.
     Sentence,
+    /// Place each sentence on its own line, break long sentences after clause punctuation,
+    /// and fill the words within clauses.
+    Clause,
+}
+
+/// How to choose line breaks when filling markup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum WrapAlgorithm {
+    /// Put as many words as fit on each line.
+    #[default]
+    Greedy,
+    /// Choose the breaks of each paragraph to minimize the raggedness of its lines.
+    ///
+    /// This applies to top-level paragraphs and the bodies of top-level list and enum items,
+    /// whose start columns are known. Other markup is filled greedily.
+    Optimal,
+}
+
+/// Whether to break lines between CJK characters when wrapping text.
+///
+/// Typst renders a line break in the source as a space, even between CJK characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum CjkBreak {
+    /// Only break text at spaces, so no space is added to the output. Typst renders every line
+    /// break in text as a space, even after CJK punctuation, so no break between CJK characters
+    /// is safe.
+    #[default]
+    Never,
+    /// Also break between CJK characters where Unicode line breaking rules allow it, and join
+    /// lines that break between CJK characters. Such line breaks are treated as insignificant,
+    /// although Typst renders them as spaces. Latin words and Hangul are never split.
+    Allow,
+}
+
+/// Trailing comma policy for comma-separated lists.
+///
+/// Commas required by the semantics, such as in the single-element array `(a,)`, are always kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum TrailingComma {
+    /// Add a trailing comma when the list spans multiple lines.
+    #[default]
+    AlwaysWhenMultiline,
+    /// Never add a trailing comma.
+    Never,
+    /// Add a trailing comma to a multi-line list only if the source has one.
+    Preserve,
+}
+
+/// Alignment of cells within the columns of tables and grids.
+///
+/// Cells are only padded when every cell in the column is single-line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum TableAlign {
+    /// Do not pad cells.
+    #[default]
+    None,
+    /// Pad cells after their content.
+    Left,
+    /// Pad cells before their content.
+    Right,
+    /// Pad cells evenly on both sides.
+    Center,
+}
+
+/// Spelling of symbols that can be written as a shorthand or by name.
+///
+/// Literal Unicode characters are never rewritten.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum SymbolStyle {
+    /// Keep symbols as written.
+    #[default]
+    Preserve,
+    /// Write symbols in math as shorthands, such as `->` and `!=`. Markup is kept as written.
+    Shorthand,
+    /// Write symbols by name, such as `arrow.r` and `eq.not`, and markup shorthands such as `--`
+    /// as `#sym` names.
+    Named,
+}
+
+/// Spelling of fractions in math.
+///
+/// Parentheses around the numerator and denominator are added or dropped as needed,
+/// so the rendered fraction does not change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum FracStyle {
+    /// Keep fractions as written.
+    #[default]
+    Preserve,
+    /// Write fractions with a slash, such as `a/b`.
+    Slash,
+    /// Write fractions as calls, such as `frac(a, b)`.
+    Call,
 }
 
 impl Default for Config {
@@ -38,8 +184,25 @@ impl Default for Config {
             max_width: 80,
             blank_lines_upper_bound: 1,
             reorder_import_items: true,
+            reorder_imports: false,
+            merge_imports: false,
             collapse_markup_spaces: false,
             wrap_mode: WrapMode::None,
+            trailing_comma: TrailingComma::AlwaysWhenMultiline,
+            magic_trailing_comma: false,
+            align_assignments: false,
+            align_trailing_comments: false,
+            table_align: TableAlign::None,
+            table_functions: vec![],
+            break_long_equations: false,
+            math_spacing: false,
+            symbol_style: SymbolStyle::Preserve,
+            inline_frac_style: FracStyle::Preserve,
+            block_frac_style: FracStyle::Preserve,
+            wrap_algorithm: WrapAlgorithm::Greedy,
+            cjk_break: CjkBreak::Never,
+            sentence_locale: "en".to_string(),
+            sentence_abbreviations: vec![],
         }
     }
 }
@@ -77,4 +240,89 @@ impl Config {
         self.wrap_mode = wrap_mode;
         self
     }
+
+    pub fn with_reorder_imports(mut self, reorder_imports: bool) -> Self {
+        self.reorder_imports = reorder_imports;
+        self
+    }
+
+    pub fn with_merge_imports(mut self, merge_imports: bool) -> Self {
+        self.merge_imports = merge_imports;
+        self
+    }
+
+    pub fn with_trailing_comma(mut self, trailing_comma: TrailingComma) -> Self {
+        self.trailing_comma = trailing_comma;
+        self
+    }
+
+    pub fn with_magic_trailing_comma(mut self, magic_trailing_comma: bool) -> Self {
+        self.magic_trailing_comma = magic_trailing_comma;
+        self
+    }
+
+    pub fn with_align_assignments(mut self, align_assignments: bool) -> Self {
+        self.align_assignments = align_assignments;
+        self
+    }
+
+    pub fn with_align_trailing_comments(mut self, align_trailing_comments: bool) -> Self {
+        self.align_trailing_comments = align_trailing_comments;
+        self
+    }
+
+    pub fn with_table_align(mut self, table_align: TableAlign) -> Self {
+        self.table_align = table_align;
+        self
+    }
+
+    pub fn with_table_functions(mut self, table_functions: Vec<String>) -> Self {
+        self.table_functions = table_functions;
+        self
+    }
+
+    pub fn with_break_long_equations(mut self, break_long_equations: bool) -> Self {
+        self.break_long_equations = break_long_equations;
+        self
+    }
+
+    pub fn with_math_spacing(mut self, math_spacing: bool) -> Self {
+        self.math_spacing = math_spacing;
+        self
+    }
+
+    pub fn with_symbol_style(mut self, symbol_style: SymbolStyle) -> Self {
+        self.symbol_style = symbol_style;
+        self
+    }
+
+    pub fn with_inline_frac_style(mut self, inline_frac_style: FracStyle) -> Self {
+        self.inline_frac_style = inline_frac_style;
+        self
+    }
+
+    pub fn with_block_frac_style(mut self, block_frac_style: FracStyle) -> Self {
+        self.block_frac_style = block_frac_style;
+        self
+    }
+
+    pub fn with_wrap_algorithm(mut self, wrap_algorithm: WrapAlgorithm) -> Self {
+        self.wrap_algorithm = wrap_algorithm;
+        self
+    }
+
+    pub fn with_cjk_break(mut self, cjk_break: CjkBreak) -> Self {
+        self.cjk_break = cjk_break;
+        self
+    }
+
+    pub fn with_sentence_locale(mut self, sentence_locale: String) -> Self {
+        self.sentence_locale = sentence_locale;
+        self
+    }
+
+    pub fn with_sentence_abbreviations(mut self, sentence_abbreviations: Vec<String>) -> Self {
+        self.sentence_abbreviations = sentence_abbreviations;
+        self
+    }
 }
@@ -5,13 +5,22 @@ pub mod partial;
 pub mod pretty;
 
 mod config;
+mod trailing_comment;
 mod utils;
+mod warning;
 
 pub use attr::AttrStore;
-pub use config::{Config, WrapMode};
-use pretty::{PrettyPrinter, prelude::*};
+pub use config::{
+    CjkBreak, Config, FracStyle, SymbolStyle, TableAlign, TrailingComma, WrapAlgorithm, WrapMode,
+};
+use pretty::{
+    PrettyPrinter,
+    context::{Context, MarkupIndent},
+    prelude::*,
+};
 use thiserror::Error;
 use typst_syntax::{Source, SyntaxNode};
+pub use warning::{Warning, WarningKind};
 
 use crate::utils::indent_4_to_2;
 
@@ -56,7 +65,8 @@ pub struct Formatter<'a> {
 impl<'a> Formatter<'a> {
     fn new(config: Config, source: Source) -> Self {
         let attr_store = AttrStore::new(source.root());
-        let printer = PrettyPrinter::new(config, attr_store);
+        let env = liteval::Env::new(source.root());
+        let printer = PrettyPrinter::new(config, attr_store).with_liteval_env(env);
         Self { source, printer }
     }
 
@@ -73,16 +83,34 @@ impl<'a> Formatter<'a> {
         doc.render_fmt(self.printer.config().max_width, &mut buf)
             .map_err(|_| Error::RenderError)?;
         let result = utils::strip_trailing_whitespace(&buf);
+        let config = self.printer.config();
+        if config.align_trailing_comments {
+            return Ok(trailing_comment::align_trailing_comments(
+                &result,
+                pretty::Mode::Markup,
+                0,
+                config.max_width,
+            ));
+        }
         Ok(result)
     }
 
+    /// Returns the requested rewrites that were skipped while rendering, in the order found.
+    ///
+    /// Warnings are collected by [`Self::render`] and [`Self::render_ir`].
+    pub fn warnings(&self) -> Vec<Warning> {
+        self.printer.warnings()
+    }
+
     fn build_doc(&'a self) -> Result<ArenaDoc<'a>, Error> {
         let root = self.source.root();
         if root.diagnosis().errors {
             return Err(Error::SyntaxError);
         }
         let markup = root.cast().unwrap();
-        let doc = self.printer.convert_markup(Default::default(), markup);
+        // The document starts at the first column, which allows filling paragraphs optimally.
+        let ctx = Context::default().with_markup_indent(Some(MarkupIndent::default()));
+        let doc = self.printer.convert_markup(ctx, markup);
         Ok(doc)
     }
 }
@@ -0,0 +1,115 @@
+use std::collections::{HashMap, HashSet};
+
+use typst_syntax::{SyntaxKind, SyntaxNode, ast::*};
+
+use super::{Liteval, Value};
+
+/// File-local constants for [`Liteval`].
+///
+/// Only top-level `let` bindings whose values can be evaluated are recorded. A name bound more
+/// than once anywhere in the file, such as by a parameter or a loop, is left out, since its value
+/// depends on the scope. Names bound anywhere also hide the builtins of the same name, and a
+/// wildcard import hides all of them, as the names it binds are unknown.
+#[derive(Debug, Clone, Default)]
+pub struct Env {
+    values: HashMap<String, Value>,
+    bound: HashSet<String>,
+    has_wildcard_import: bool,
+}
+
+impl Env {
+    /// Builds the environment from the root of a source file.
+    pub fn new(root: &SyntaxNode) -> Self {
+        let mut counts = HashMap::new();
+        let mut has_wildcard_import = false;
+        count_bindings(root, &mut counts, &mut has_wildcard_import);
+        let mut env = Self {
+            values: HashMap::new(),
+            bound: counts.keys().cloned().collect(),
+            has_wildcard_import,
+        };
+        let Some(markup) = root.cast::<Markup>() else {
+            return env;
+        };
+        for expr in markup.exprs() {
+            let Expr::LetBinding(binding) = expr else {
+                continue;
+            };
+            let LetBindingKind::Normal(Pattern::Normal(Expr::Ident(ident))) = binding.kind() else {
+                continue;
+            };
+            if counts.get(ident.as_str()) == Some(&1)
+                && let Some(init) = binding.init()
+                && let Ok(value) = init.liteval_in(&env)
+            {
+                env.values.insert(ident.as_str().to_string(), value);
+            }
+        }
+        env
+    }
+
+    /// Returns the value of a file-local constant.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.values.get(name)
+    }
+
+    /// Returns whether the name refers to the builtin of the standard library.
+    pub fn is_builtin(&self, name: &str) -> bool {
+        !self.has_wildcard_import && !self.bound.contains(name)
+    }
+}
+
+/// Counts the names bound by let bindings, parameters, loops and imports in the node,
+/// and records whether it contains a wildcard import.
+fn count_bindings(
+    node: &SyntaxNode,
+    counts: &mut HashMap<String, usize>,
+    has_wildcard_import: &mut bool,
+) {
+    let bindings = match node.kind() {
+        SyntaxKind::LetBinding => node.cast::<LetBinding>().map(|it| it.kind().bindings()),
+        SyntaxKind::ForLoop => node.cast::<ForLoop>().map(|it| it.pattern().bindings()),
+        SyntaxKind::Params => node.cast::<Params>().map(|params| {
+            params
+                .children()
+                .flat_map(|param| match param {
+                    Param::Pos(pattern) => pattern.bindings(),
+                    Param::Named(named) => vec![named.name()],
+                    Param::Spread(spread) => spread.sink_ident().into_iter().collect(),
+                })
+                .collect()
+        }),
+        SyntaxKind::ModuleImport => {
+            if let Some(import) = node.cast::<ModuleImport>() {
+                *has_wildcard_import |= matches!(import.imports(), Some(Imports::Wildcard));
+                for name in import_bindings(import) {
+                    *counts.entry(name).or_default() += 1;
+                }
+            }
+            None
+        }
+        _ => None,
+    };
+    for ident in bindings.into_iter().flatten() {
+        *counts.entry(ident.get().to_string()).or_default() += 1;
+    }
+    for child in node.children() {
+        count_bindings(child, counts, has_wildcard_import);
+    }
+}
+
+/// Returns the names bound by an import statement. Names from wildcard imports are unknown.
+pub(crate) fn import_bindings(import: ModuleImport) -> Vec<String> {
+    let mut names = vec![];
+    if let Some(new_name) = import.new_name() {
+        names.push(new_name.get().to_string());
+    } else if import.imports().is_none()
+        && let Ok(name) = import.bare_name()
+    {
+        names.push(name.to_string());
+    }
+    if let Some(Imports::Items(items)) = import.imports() {
+        names.extend(items.iter().map(|item| item.bound_name().get().to_string()));
+    }
+    names
+}
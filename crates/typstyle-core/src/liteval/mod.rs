@@ -1,7 +1,14 @@
 //! Evaluate simple constant Typst expressions in code mode without scopes or VMs.
 //!
+//! Identifiers are looked up in a file-local [`Env`] of constants, and a few pure builtins
+//! such as `range`, `array.len` and `calc.max` are supported.
+//!
 //! Currently, this is only used for determine table columns.
 
+mod env;
+
+pub use env::Env;
+pub(crate) use env::import_bindings;
 use typst_syntax::ast::*;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -22,64 +29,95 @@ pub enum EvalError {
 pub type EvalResult = Result<Value, EvalError>;
 
 pub trait Liteval {
-    fn liteval(&self) -> EvalResult;
+    /// Evaluates the expression with the constants in the environment.
+    fn liteval_in(&self, env: &Env) -> EvalResult;
+
+    /// Evaluates the expression without any constants.
+    fn liteval(&self) -> EvalResult {
+        self.liteval_in(&Env::default())
+    }
 }
 
 impl Liteval for Expr<'_> {
-    fn liteval(&self) -> EvalResult {
+    fn liteval_in(&self, env: &Env) -> EvalResult {
         match self {
-            Expr::None(v) => v.liteval(),
-            Expr::Auto(v) => v.liteval(),
-            Expr::Int(v) => v.liteval(),
-            Expr::Parenthesized(v) => v.liteval(),
-            Expr::Array(v) => v.liteval(),
-            Expr::Unary(v) => v.liteval(),
-            Expr::Binary(v) => v.liteval(),
+            Expr::None(v) => v.liteval_in(env),
+            Expr::Auto(v) => v.liteval_in(env),
+            Expr::Int(v) => v.liteval_in(env),
+            Expr::Ident(v) => v.liteval_in(env),
+            Expr::Parenthesized(v) => v.liteval_in(env),
+            Expr::Array(v) => v.liteval_in(env),
+            Expr::Unary(v) => v.liteval_in(env),
+            Expr::Binary(v) => v.liteval_in(env),
+            Expr::FuncCall(v) => v.liteval_in(env),
             _ => Err(EvalError::NotSupported),
         }
     }
 }
 
 impl Liteval for None<'_> {
-    fn liteval(&self) -> EvalResult {
+    fn liteval_in(&self, _env: &Env) -> EvalResult {
         Ok(Value::None)
     }
 }
 
 impl Liteval for Auto<'_> {
-    fn liteval(&self) -> EvalResult {
+    fn liteval_in(&self, _env: &Env) -> EvalResult {
         Ok(Value::Auto)
     }
 }
 
 impl Liteval for Int<'_> {
-    fn liteval(&self) -> EvalResult {
+    fn liteval_in(&self, _env: &Env) -> EvalResult {
         Ok(Value::Int(self.get()))
     }
 }
 
+impl Liteval for Ident<'_> {
+    fn liteval_in(&self, env: &Env) -> EvalResult {
+        env.get(self.as_str())
+            .cloned()
+            .ok_or(EvalError::NotSupported)
+    }
+}
+
 impl Liteval for Parenthesized<'_> {
-    fn liteval(&self) -> EvalResult {
-        self.expr().liteval()
+    fn liteval_in(&self, env: &Env) -> EvalResult {
+        self.expr().liteval_in(env)
     }
 }
 
 impl Liteval for Array<'_> {
-    fn liteval(&self) -> EvalResult {
-        Ok(Value::Array(self.items().count()))
+    fn liteval_in(&self, env: &Env) -> EvalResult {
+        let mut len: usize = 0;
+        for item in self.items() {
+            let n = match item {
+                ArrayItem::Pos(_) => 1,
+                ArrayItem::Spread(spread) => match spread.expr().liteval_in(env)? {
+                    Value::None => 0,
+                    Value::Array(n) => n,
+                    _ => return Err(EvalError::InvalidOperation),
+                },
+            };
+            len = len.checked_add(n).ok_or(EvalError::InvalidOperation)?;
+        }
+        Ok(Value::Array(len))
     }
 }
 
 impl Liteval for Unary<'_> {
-    fn liteval(&self) -> EvalResult {
-        let expr = self.expr().liteval()?;
+    fn liteval_in(&self, env: &Env) -> EvalResult {
+        let expr = self.expr().liteval_in(env)?;
         match self.op() {
             UnOp::Pos => match expr {
                 Value::Int(i) => Ok(Value::Int(i)),
                 _ => Err(EvalError::InvalidOperation),
             },
             UnOp::Neg => match expr {
-                Value::Int(i) => Ok(Value::Int(-i)),
+                Value::Int(i) => i
+                    .checked_neg()
+                    .map(Value::Int)
+                    .ok_or(EvalError::InvalidOperation),
                 _ => Err(EvalError::InvalidOperation),
             },
             UnOp::Not => Err(EvalError::NotSupported),
@@ -87,34 +125,115 @@ impl Liteval for Unary<'_> {
     }
 }
 impl Liteval for Binary<'_> {
-    fn liteval(&self) -> EvalResult {
-        let lhs = self.lhs().liteval()?;
-        let rhs = self.rhs().liteval()?;
-        match self.op() {
+    fn liteval_in(&self, env: &Env) -> EvalResult {
+        let lhs = self.lhs().liteval_in(env)?;
+        let rhs = self.rhs().liteval_in(env)?;
+        let result = match self.op() {
             BinOp::Add => match (lhs, rhs) {
-                (Value::Int(l), Value::Int(r)) => Ok(Value::Int(l + r)),
-                (Value::Array(l), Value::Array(r)) => Ok(Value::Array(l + r)),
-                _ => Err(EvalError::InvalidOperation),
+                (Value::Int(l), Value::Int(r)) => l.checked_add(r).map(Value::Int),
+                (Value::Array(l), Value::Array(r)) => l.checked_add(r).map(Value::Array),
+                _ => Option::None,
             },
             BinOp::Sub => match (lhs, rhs) {
-                (Value::Int(l), Value::Int(r)) => Ok(Value::Int(l - r)),
-                _ => Err(EvalError::InvalidOperation),
+                (Value::Int(l), Value::Int(r)) => l.checked_sub(r).map(Value::Int),
+                _ => Option::None,
             },
             BinOp::Mul => match (lhs, rhs) {
-                (Value::Int(l), Value::Int(r)) => Ok(Value::Int(l * r)),
-                (Value::Array(l), Value::Int(r)) if r >= 0 => Ok(Value::Array(l * r as usize)),
-                (Value::Int(l), Value::Array(r)) if l >= 0 => Ok(Value::Array(l as usize * r)),
-                _ => Err(EvalError::InvalidOperation),
+                (Value::Int(l), Value::Int(r)) => l.checked_mul(r).map(Value::Int),
+                (Value::Array(n), Value::Int(i)) | (Value::Int(i), Value::Array(n)) => {
+                    usize::try_from(i)
+                        .ok()
+                        .and_then(|i| n.checked_mul(i))
+                        .map(Value::Array)
+                }
+                _ => Option::None,
             },
             BinOp::Div => match (lhs, rhs) {
-                (Value::Int(l), Value::Int(r)) if r != 0 => Ok(Value::Int(l / r)),
-                _ => Err(EvalError::InvalidOperation),
+                (Value::Int(l), Value::Int(r)) => l.checked_div(r).map(Value::Int),
+                _ => Option::None,
             },
+            _ => return Err(EvalError::NotSupported),
+        };
+        // Overflows are invalid, as in Typst.
+        result.ok_or(EvalError::InvalidOperation)
+    }
+}
+
+impl Liteval for FuncCall<'_> {
+    fn liteval_in(&self, env: &Env) -> EvalResult {
+        let mut pos = vec![];
+        let mut step = Option::None;
+        for arg in self.args().items() {
+            match arg {
+                Arg::Pos(expr) => pos.push(expr),
+                Arg::Named(named) if named.name().as_str() == "step" => step = Some(named.expr()),
+                _ => return Err(EvalError::NotSupported),
+            }
+        }
+        match self.callee() {
+            Expr::Ident(ident) if ident.as_str() == "range" && env.is_builtin("range") => {
+                let ints = eval_ints(env, pos.iter().copied().chain(step))?;
+                let (start, end, step) = match (ints.as_slice(), step.is_some()) {
+                    (&[end], false) => (0, end, 1),
+                    (&[end, step], true) => (0, end, step),
+                    (&[start, end], false) => (start, end, 1),
+                    (&[start, end, step], true) => (start, end, step),
+                    _ => return Err(EvalError::InvalidOperation),
+                };
+                // Computed in `i128`, which holds the span and step of any `i64` bounds.
+                let (start, end) = (start as i128, end as i128);
+                let (span, step) = match step {
+                    0 => return Err(EvalError::InvalidOperation),
+                    1.. => (end - start, step as i128),
+                    _ => (start - end, -(step as i128)),
+                };
+                let len = (span.max(0) + step - 1) / step;
+                usize::try_from(len)
+                    .map(Value::Array)
+                    .map_err(|_| EvalError::InvalidOperation)
+            }
+            Expr::FieldAccess(access) if step.is_none() => {
+                let field = access.field().as_str();
+                if let Expr::Ident(module) = access.target()
+                    && module.as_str() == "calc"
+                    && env.is_builtin("calc")
+                {
+                    let ints = eval_ints(env, pos)?.into_iter();
+                    let result = match field {
+                        "max" => ints.max(),
+                        "min" => ints.min(),
+                        _ => return Err(EvalError::NotSupported),
+                    };
+                    return result.map(Value::Int).ok_or(EvalError::InvalidOperation);
+                }
+                // Methods of arrays.
+                match (access.target().liteval_in(env)?, field, pos.len()) {
+                    (Value::Array(n), "len", 0) => Ok(Value::Int(n as i64)),
+                    (Value::Array(n), "map", 1) | (Value::Array(n), "rev", 0) => {
+                        Ok(Value::Array(n))
+                    }
+                    _ => Err(EvalError::NotSupported),
+                }
+            }
             _ => Err(EvalError::NotSupported),
         }
     }
 }
 
+/// Evaluates the expressions, which must all be integers.
+fn eval_ints<'a>(
+    env: &Env,
+    exprs: impl IntoIterator<Item = Expr<'a>>,
+) -> Result<Vec<i64>, EvalError> {
+    exprs
+        .into_iter()
+        .map(|expr| match expr.liteval_in(env)? {
+            Value::Int(i) => Ok(i),
+            _ => Err(EvalError::InvalidOperation),
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,5 +260,75 @@ mod tests {
         test_liteval("(1, 2) + (3, 4, 5)", Array(5));
         test_liteval("(1,) * 2 + 2 * (3, 4)", Array(6));
         test_liteval("((1,) * 2 + 2 * (3,)) * 4", Array(16));
+        test_liteval("(..(1, 2), 3, ..none)", Array(3));
+    }
+
+    #[test]
+    fn test_builtins() {
+        use Value::*;
+
+        test_liteval("range(4)", Array(4));
+        test_liteval("range(4).map(_ => 1fr)", Array(4));
+        test_liteval("range(1, 10, step: 3)", Array(3));
+        test_liteval("range(5, 0, step: -2)", Array(3));
+        test_liteval("range(3).len()", Int(3));
+        test_liteval("calc.max(2, 3) * (1fr,)", Array(3));
+        test_liteval("calc.min(2, 3)", Int(2));
+    }
+
+    fn test_liteval_in(markup: &str, code: &str, expected: EvalResult) {
+        let env = Env::new(&typst_syntax::parse(markup));
+        let root = typst_syntax::parse_code(code);
+        let expr = root.cast::<Code>().unwrap().exprs().next().unwrap();
+        assert_eq!(expr.liteval_in(&env), expected, "expr: {expr:#?}");
+    }
+
+    #[test]
+    fn test_env() {
+        use Value::*;
+
+        test_liteval_in("#let n = 3", "(auto,) * n", Ok(Array(3)));
+        test_liteval_in("#let cols = 3 * (1fr,)", "cols", Ok(Array(3)));
+        test_liteval_in("#let a = (1, 2, 3)", "a.len()", Ok(Int(3)));
+        test_liteval_in(
+            "#let n = 2\n#let m = n + 1",
+            "(..(1fr,) * m, auto)",
+            Ok(Array(4)),
+        );
+        test_liteval_in("#let n = x", "n", Err(EvalError::NotSupported));
+        test_liteval_in(
+            "#let n = 3\n#let f(n) = n",
+            "n",
+            Err(EvalError::NotSupported),
+        );
+        test_liteval_in(
+            "#let range(x) = x",
+            "range(3)",
+            Err(EvalError::NotSupported),
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        use Value::*;
+
+        test_liteval("range(9223372036854775807)", Array(9223372036854775807));
+        test_liteval(
+            "range(0, -9223372036854775807, step: -9223372036854775807 - 1)",
+            Array(1),
+        );
+        for code in [
+            "9223372036854775807 + 1",
+            "-(-9223372036854775807 - 1)",
+            "(-9223372036854775807 - 1) / -1",
+            "(1fr,) * 9223372036854775807 * 4",
+        ] {
+            test_liteval_in("", code, Err(EvalError::InvalidOperation));
+        }
+    }
+
+    #[test]
+    fn test_range_step_is_named() {
+        test_liteval_in("", "range(5, 0, -2)", Err(EvalError::InvalidOperation));
     }
 }
@@ -10,10 +10,12 @@ use std::{borrow::Cow, ops::Range};
 
 use itertools::Itertools;
 use typst_syntax::{LinkedNode, Source, Span, SyntaxKind, SyntaxNode, ast::*};
+use unicode_width::UnicodeWidthStr;
 
 use crate::{
-    AttrStore, Error, PrettyPrinter, Typstyle,
+    AttrStore, Error, PrettyPrinter, Typstyle, liteval,
     pretty::Mode,
+    trailing_comment,
     utils::{self, indent_4_to_2},
 };
 
@@ -59,15 +61,28 @@ impl Typstyle {
         };
 
         let attrs = AttrStore::new(&node); // Here we only compute the attributes of that subtree.
-        let printer = PrettyPrinter::new(self.config.clone(), attrs);
+        let printer = PrettyPrinter::new(self.config.clone(), attrs)
+            .with_liteval_env(liteval::Env::new(source.root()));
         let doc = printer.try_convert_with_mode(&node, mode)?;
 
         // Infer indent from context.
         let indent = utils::count_spaces_after_last_newline(source.text(), node_range.start);
-        let text = doc
+        let mut text = doc
             .nest(indent as isize)
             .print(self.config.max_width)
             .to_string();
+        if self.config.align_trailing_comments {
+            let line_start = source.text()[..node_range.start]
+                .rfind('\n')
+                .map_or(0, |i| i + 1);
+            let first_column = source.text()[line_start..node_range.start].width();
+            text = trailing_comment::align_trailing_comments(
+                &text,
+                mode,
+                first_column,
+                self.config.max_width,
+            );
+        }
 
         Ok(RangeResult {
             source_range: node_range,
@@ -96,7 +111,8 @@ impl Typstyle {
         };
 
         let attrs = AttrStore::new(&node);
-        let printer = PrettyPrinter::new(self.config.clone(), attrs);
+        let printer = PrettyPrinter::new(self.config.clone(), attrs)
+            .with_liteval_env(liteval::Env::new(source.root()));
         let doc = printer.try_convert_with_mode(&node, mode)?;
 
         let ir = indent_4_to_2(&format!("{doc:#?}"));
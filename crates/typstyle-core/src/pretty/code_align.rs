@@ -0,0 +1,255 @@
+use rustc_hash::FxHashMap;
+use typst_syntax::{SyntaxKind, SyntaxNode, ast::*};
+use unicode_width::UnicodeWidthStr;
+
+use super::{Context, PrettyPrinter, prelude::*, style::FoldStyle, util::has_comment_children};
+use crate::ext::StrExt;
+
+/// The paddings that align the items of runs on consecutive lines, keyed by node address.
+#[derive(Default)]
+pub(super) struct Paddings(FxHashMap<*const SyntaxNode, usize>);
+
+/// An item to align, with the width before its padding and the width of the rest of its line.
+struct AlignItem<'a> {
+    node: &'a SyntaxNode,
+    head_width: usize,
+    tail_width: usize,
+}
+
+impl Paddings {
+    /// Returns the padding of the node, or zero if it is not aligned.
+    pub fn get(&self, node: &SyntaxNode) -> usize {
+        self.0.get(&(node as *const _)).copied().unwrap_or_default()
+    }
+
+    /// Computes the paddings from the widths of the items, where `None` ends a run.
+    ///
+    /// A run is not aligned if any of its padded lines would exceed `max_width`.
+    fn from_runs<'a>(
+        items: impl IntoIterator<Item = Option<AlignItem<'a>>>,
+        max_width: usize,
+    ) -> Self {
+        let mut paddings = Self::default();
+        let mut run = vec![];
+        for item in items.into_iter().chain([None]) {
+            if let Some(item) = item {
+                run.push(item);
+                continue;
+            }
+            let head_width = run.iter().map(|it| it.head_width).max().unwrap_or_default();
+            if run.len() > 1 && run.iter().all(|it| head_width + it.tail_width <= max_width) {
+                paddings.0.extend(
+                    run.iter()
+                        .map(|it| (it.node as *const _, head_width - it.head_width)),
+                );
+            }
+            run.clear();
+        }
+        paddings
+    }
+}
+
+impl<'a> PrettyPrinter<'a> {
+    /// Computes the paddings before `=` that align runs of single-line let bindings.
+    ///
+    /// Each item is the let binding on a line, or `None` for a line that ends a run.
+    pub(super) fn let_binding_paddings(
+        &'a self,
+        ctx: Context,
+        lines: impl IntoIterator<Item = Option<LetBinding<'a>>>,
+    ) -> Paddings {
+        if !self.config.align_assignments {
+            return Paddings::default();
+        }
+        let items = lines.into_iter().map(|binding| {
+            let binding = binding?;
+            let (head_width, value_width) = self.let_binding_widths(ctx, binding)?;
+            Some(AlignItem {
+                node: binding.to_untyped(),
+                head_width,
+                tail_width: " = ".len() + value_width,
+            })
+        });
+        Paddings::from_runs(items, self.config.max_width)
+    }
+
+    /// Computes the paddings before `=` that align the let bindings among the children of code.
+    ///
+    /// Only bindings starting their lines are aligned. Other exprs, comments on their own lines
+    /// and blank lines end a run, while trailing comments do not.
+    pub(super) fn code_let_binding_paddings(
+        &'a self,
+        ctx: Context,
+        children: impl IntoIterator<Item = &'a SyntaxNode>,
+    ) -> Paddings {
+        let mut at_line_start = true;
+        let lines = children.into_iter().filter_map(|child| {
+            let binding = match child.kind() {
+                SyntaxKind::Space => {
+                    let linebreaks = child.leaf_text().count_linebreaks();
+                    at_line_start |= linebreaks > 0;
+                    return (linebreaks > 1).then_some(None);
+                }
+                SyntaxKind::Semicolon | SyntaxKind::LeftBrace | SyntaxKind::RightBrace => {
+                    return None;
+                }
+                SyntaxKind::LineComment | SyntaxKind::BlockComment if !at_line_start => {
+                    return None;
+                }
+                _ if at_line_start => child.cast(),
+                _ => None,
+            };
+            at_line_start = false;
+            Some(binding)
+        });
+        self.let_binding_paddings(ctx, lines)
+    }
+
+    /// Converts an expr, padding its `=` if it is an aligned let binding.
+    pub(super) fn convert_padded_expr(
+        &'a self,
+        ctx: Context,
+        expr: Expr<'a>,
+        padding: usize,
+    ) -> ArenaDoc<'a> {
+        match expr {
+            Expr::LetBinding(binding) if padding > 0 => {
+                self.convert_padded_let_binding(ctx, binding, padding)
+            }
+            _ => self.convert_expr(ctx, expr),
+        }
+    }
+
+    /// Computes the paddings after `:` that align the values of runs of named items in a list
+    /// laid out one item per line.
+    ///
+    /// The paddings only take effect when the list breaks, whether it is written so or too wide.
+    /// Lists that never break, or whose last item is kept on the line of the others, are not
+    /// aligned. Positional items, blank lines and comments on their own lines end a run.
+    pub(super) fn named_item_paddings(
+        &'a self,
+        ctx: Context,
+        children: impl IntoIterator<Item = &'a SyntaxNode>,
+        fold_style: FoldStyle,
+    ) -> Paddings {
+        if !self.config.align_assignments {
+            return Paddings::default();
+        }
+        let children = children.into_iter().collect::<Vec<_>>();
+        let has_line_comment = children
+            .iter()
+            .any(|child| child.kind() == SyntaxKind::LineComment);
+        if matches!(fold_style, FoldStyle::Always | FoldStyle::Compact) && !has_line_comment {
+            return Paddings::default();
+        }
+        let mut at_line_start = true;
+        let items = children.into_iter().filter_map(|child| {
+            let item = match child.kind() {
+                SyntaxKind::Named | SyntaxKind::Keyed => {
+                    self.named_item_widths(ctx, child)
+                        .map(|(head_width, value_width)| AlignItem {
+                            node: child,
+                            head_width,
+                            // The item is indented by one level inside the list.
+                            tail_width: self.config.tab_spaces
+                                + ": ".len()
+                                + value_width
+                                + ",".len(),
+                        })
+                }
+                SyntaxKind::Comma => return None,
+                SyntaxKind::Space => {
+                    let linebreaks = child.leaf_text().count_linebreaks();
+                    at_line_start |= linebreaks > 0;
+                    return (linebreaks > 1).then_some(None);
+                }
+                SyntaxKind::LineComment | SyntaxKind::BlockComment if !at_line_start => {
+                    return None;
+                }
+                _ => None,
+            };
+            at_line_start = false;
+            Some(item)
+        });
+        Paddings::from_runs(items, self.config.max_width)
+    }
+
+    /// Returns the width of `let` and the pattern or the function head of a single-line binding,
+    /// and the width of the first line of its value.
+    fn let_binding_widths(
+        &'a self,
+        ctx: Context,
+        binding: LetBinding<'a>,
+    ) -> Option<(usize, usize)> {
+        let node = binding.to_untyped();
+        if binding.init().is_none()
+            || node.full_text().has_linebreak()
+            || has_comment_children(node)
+            || self.attr_store.is_format_disabled(node)
+        {
+            return None;
+        }
+        let (head, value) = match binding.kind() {
+            LetBindingKind::Normal(pattern) => {
+                (self.convert_pattern(ctx, pattern), binding.init()?)
+            }
+            LetBindingKind::Closure(name) => {
+                let Some(Expr::Closure(closure)) = binding.init() else {
+                    return None;
+                };
+                if has_comment_children(closure.to_untyped()) {
+                    return None;
+                }
+                let head =
+                    self.convert_ident(name) + self.convert_params(ctx, closure.params(), false);
+                (head, closure.body())
+            }
+        };
+        let head_width = "let ".len() + self.render_single_line(head)?.width();
+        Some((
+            head_width,
+            self.first_line_width(self.convert_expr(ctx, value))?,
+        ))
+    }
+
+    /// Returns the width of the key of a named or keyed item, and the width of the first line of
+    /// its value.
+    fn named_item_widths(&'a self, ctx: Context, item: &'a SyntaxNode) -> Option<(usize, usize)> {
+        if has_comment_children(item) || self.attr_store.is_format_disabled(item) {
+            return None;
+        }
+        let (key_width, value) = if let Some(named) = item.cast::<Named>() {
+            (named.name().as_str().width(), named.expr())
+        } else {
+            let keyed = item.cast::<Keyed>()?;
+            let key = self.render_single_line(self.convert_expr(ctx, keyed.key()))?;
+            (key.width(), keyed.expr())
+        };
+        Some((
+            key_width,
+            self.first_line_width(self.convert_expr(ctx, value))?,
+        ))
+    }
+
+    /// Returns the width of the first line of the rendered doc.
+    fn first_line_width(&'a self, doc: ArenaDoc<'a>) -> Option<usize> {
+        let mut buf = String::new();
+        doc.render_fmt(self.config.max_width, &mut buf).ok()?;
+        Some(buf.lines().next().unwrap_or_default().width())
+    }
+
+    pub(super) fn render_single_line(&'a self, doc: ArenaDoc<'a>) -> Option<String> {
+        let mut buf = String::new();
+        doc.render_fmt(self.config.max_width, &mut buf).ok()?;
+        (!buf.has_linebreak()).then_some(buf)
+    }
+
+    /// Returns the given number of spaces.
+    pub(super) fn padding(&'a self, padding: usize) -> ArenaDoc<'a> {
+        if padding == 0 {
+            self.arena.nil()
+        } else {
+            self.arena.text(" ".repeat(padding))
+        }
+    }
+}
@@ -10,10 +10,22 @@ use crate::ext::{BoolExt, StrExt};
 
 impl<'a> PrettyPrinter<'a> {
     pub(super) fn convert_named(&'a self, ctx: Context, named: Named<'a>) -> ArenaDoc<'a> {
+        self.convert_padded_named(ctx, named, 0)
+    }
+
+    /// Converts a named item, padding its value to align it with its neighbors.
+    pub(super) fn convert_padded_named(
+        &'a self,
+        ctx: Context,
+        named: Named<'a>,
+        padding: usize,
+    ) -> ArenaDoc<'a> {
         let mut seen_name = false;
         self.convert_flow_like(ctx, named.to_untyped(), |ctx, child, _| {
             if child.kind() == SyntaxKind::Colon {
-                FlowItem::tight_spaced(self.arena.text(":"))
+                FlowItem::tight_spaced(
+                    self.arena.text(":") + self.padding(padding).when_group_break(),
+                )
             } else if let Some(expr) = child.cast() {
                 // expr
                 FlowItem::spaced_before(self.convert_expr(ctx, expr), seen_name.replace(true))
@@ -27,10 +39,22 @@ impl<'a> PrettyPrinter<'a> {
     }
 
     pub(super) fn convert_keyed(&'a self, ctx: Context, keyed: Keyed<'a>) -> ArenaDoc<'a> {
+        self.convert_padded_keyed(ctx, keyed, 0)
+    }
+
+    /// Converts a keyed item, padding its value to align it with its neighbors.
+    pub(super) fn convert_padded_keyed(
+        &'a self,
+        ctx: Context,
+        keyed: Keyed<'a>,
+        padding: usize,
+    ) -> ArenaDoc<'a> {
         let mut seen_key = false;
         self.convert_flow_like(ctx, keyed.to_untyped(), |ctx, child, _| {
             if child.kind() == SyntaxKind::Colon {
-                FlowItem::tight_spaced(self.arena.text(":"))
+                FlowItem::tight_spaced(
+                    self.arena.text(":") + self.padding(padding).when_group_break(),
+                )
             } else if let Some(expr) = child.cast() {
                 // key, expr
                 FlowItem::spaced_before(self.convert_expr(ctx, expr), seen_key.replace(true))
@@ -88,6 +112,16 @@ impl<'a> PrettyPrinter<'a> {
     }
 
     pub(super) fn convert_closure(&'a self, ctx: Context, closure: Closure<'a>) -> ArenaDoc<'a> {
+        self.convert_padded_closure(ctx, closure, 0)
+    }
+
+    /// Converts a closure, padding its `=` to align it with its neighbors.
+    fn convert_padded_closure(
+        &'a self,
+        ctx: Context,
+        closure: Closure<'a>,
+        padding: usize,
+    ) -> ArenaDoc<'a> {
         enum LookAhead {
             Name,
             Params,
@@ -101,7 +135,7 @@ impl<'a> PrettyPrinter<'a> {
         };
         self.convert_flow_like(ctx, closure.to_untyped(), |ctx, child, _| {
             if child.kind() == SyntaxKind::Eq {
-                return FlowItem::spaced(self.arena.text("="));
+                return FlowItem::spaced(self.padding(padding) + self.arena.text("="));
             } else if child.kind() == SyntaxKind::Arrow {
                 return FlowItem::spaced(self.arena.text("=>"));
             }
@@ -139,10 +173,25 @@ impl<'a> PrettyPrinter<'a> {
         &'a self,
         ctx: Context,
         let_binding: LetBinding<'a>,
+    ) -> ArenaDoc<'a> {
+        self.convert_padded_let_binding(ctx, let_binding, 0)
+    }
+
+    /// Converts a let binding, padding its `=` to align it with its neighbors.
+    pub(super) fn convert_padded_let_binding(
+        &'a self,
+        ctx: Context,
+        let_binding: LetBinding<'a>,
+        padding: usize,
     ) -> ArenaDoc<'a> {
         self.convert_flow_like(ctx, let_binding.to_untyped(), |ctx, child, _| {
             if child.kind() == SyntaxKind::Eq {
-                FlowItem::spaced(self.arena.text("="))
+                FlowItem::spaced(self.padding(padding) + self.arena.text("="))
+            } else if padding > 0
+                && let Some(closure) = child.cast::<Closure>()
+                && closure.name().is_some()
+            {
+                FlowItem::spaced(self.convert_padded_closure(ctx, closure, padding))
             } else if let Some(pattern) = child.cast() {
                 // Must try pattern before expr
                 FlowItem::spaced(self.convert_pattern(ctx, pattern))
@@ -34,6 +34,7 @@ impl<'a> PrettyPrinter<'a> {
 
         let can_fold = code_block.body().exprs().count() <= 1
             && !has_comment_children(code_block.to_untyped());
+        let paddings = self.code_let_binding_paddings(ctx, nodes.iter().copied());
         ListStylist::new(self)
             .disallow_front_comment()
             .with_fold_style(if can_fold {
@@ -42,8 +43,8 @@ impl<'a> PrettyPrinter<'a> {
                 FoldStyle::Never
             })
             .keep_linebreak(self.config.blank_lines_upper_bound)
-            .process_iterable(ctx, nodes.into_iter(), |ctx, expr| {
-                self.convert_expr(ctx, expr)
+            .process_iterable(ctx, nodes.into_iter(), |ctx, expr: Expr<'a>| {
+                self.convert_padded_expr(ctx, expr, paddings.get(expr.to_untyped()))
             })
             .print_doc(ListStyle {
                 separator: "",
@@ -56,13 +57,14 @@ impl<'a> PrettyPrinter<'a> {
     /// Only used for partial format.
     pub(super) fn convert_code(&'a self, ctx: Context, code: Code<'a>) -> ArenaDoc<'a> {
         let ctx = ctx.with_mode(Mode::Code);
+        let paddings = self.code_let_binding_paddings(ctx, code.to_untyped().children());
 
         ListStylist::new(self)
             .disallow_front_comment()
             .with_fold_style(FoldStyle::Never)
             .keep_linebreak(self.config.blank_lines_upper_bound)
-            .process_iterable(ctx, code.to_untyped().children(), |ctx, expr| {
-                self.convert_expr(ctx, expr)
+            .process_iterable(ctx, code.to_untyped().children(), |ctx, expr: Expr<'a>| {
+                self.convert_padded_expr(ctx, expr, paddings.get(expr.to_untyped()))
             })
             .print_doc(ListStyle {
                 separator: "",
@@ -143,12 +145,14 @@ impl<'a> PrettyPrinter<'a> {
         let ctx = ctx.with_mode(Mode::CodeCont);
 
         let all_spread = dict.items().all(|item| matches!(item, DictItem::Spread(_)));
+        let fold_style = self.get_fold_style(ctx, dict);
+        let paddings = self.named_item_paddings(ctx, dict.to_untyped().children(), fold_style);
 
         ListStylist::new(self)
-            .with_fold_style(self.get_fold_style(ctx, dict))
+            .with_fold_style(fold_style)
             .keep_linebreak(self.config.blank_lines_upper_bound)
-            .process_list(ctx, dict.to_untyped(), |ctx, node| {
-                self.convert_dict_item(ctx, node)
+            .process_list(ctx, dict.to_untyped(), |ctx, node: DictItem<'a>| {
+                self.convert_dict_item(ctx, node, paddings.get(node.to_untyped()))
             })
             .print_doc(ListStyle {
                 delim: (if all_spread { "(:" } else { "(" }, ")"),
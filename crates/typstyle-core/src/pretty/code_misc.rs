@@ -25,24 +25,35 @@ impl<'a> PrettyPrinter<'a> {
         &'a self,
         ctx: Context,
         dict_item: DictItem<'a>,
+        padding: usize,
     ) -> ArenaDoc<'a> {
         if let Some(res) = self.check_disabled(dict_item.to_untyped()) {
             return res;
         }
         match dict_item {
-            DictItem::Named(n) => self.convert_named(ctx, n),
-            DictItem::Keyed(k) => self.convert_keyed(ctx, k),
+            DictItem::Named(n) => self.convert_padded_named(ctx, n, padding),
+            DictItem::Keyed(k) => self.convert_padded_keyed(ctx, k, padding),
             DictItem::Spread(s) => self.convert_spread(ctx, s),
         }
     }
 
     pub(super) fn convert_arg(&'a self, ctx: Context, arg: Arg<'a>) -> ArenaDoc<'a> {
+        self.convert_padded_arg(ctx, arg, 0)
+    }
+
+    /// Converts an argument, padding the value of a named argument to align it.
+    pub(super) fn convert_padded_arg(
+        &'a self,
+        ctx: Context,
+        arg: Arg<'a>,
+        padding: usize,
+    ) -> ArenaDoc<'a> {
         if let Some(res) = self.check_disabled(arg.to_untyped()) {
             return res;
         }
         match arg {
             Arg::Pos(p) => self.convert_expr(ctx, p),
-            Arg::Named(n) => self.convert_named(ctx, n),
+            Arg::Named(n) => self.convert_padded_named(ctx, n, padding),
             Arg::Spread(s) => self.convert_spread(ctx, s),
         }
     }
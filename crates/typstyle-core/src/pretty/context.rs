@@ -3,6 +3,10 @@ pub struct Context {
     pub mode: Mode,
     pub break_suppressed: bool,
     pub align_mode: AlignMode,
+    /// Whether the current equation is a block equation.
+    pub in_block_equation: bool,
+    /// The columns where the lines of the current markup start, if known.
+    pub markup_indent: Option<MarkupIndent>,
 }
 
 impl Context {
@@ -22,6 +26,20 @@ impl Context {
         }
     }
 
+    pub fn in_equation(self, is_block: bool) -> Self {
+        Self {
+            in_block_equation: is_block,
+            ..self
+        }
+    }
+
+    pub fn with_markup_indent(self, markup_indent: Option<MarkupIndent>) -> Self {
+        Self {
+            markup_indent,
+            ..self
+        }
+    }
+
     pub fn suppress_breaks(self) -> Self {
         Self {
             break_suppressed: true,
@@ -72,6 +90,20 @@ impl Mode {
     }
 }
 
+/// The columns where the first and the following lines of markup start.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MarkupIndent {
+    pub first: usize,
+    pub rest: usize,
+}
+
+impl MarkupIndent {
+    /// Returns the column of nodes starting a line, if it is the same for all lines.
+    pub fn column(self) -> Option<usize> {
+        (self.first == self.rest).then_some(self.rest)
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum AlignMode {
     #[default]
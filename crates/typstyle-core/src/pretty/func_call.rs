@@ -39,8 +39,10 @@ impl<'a> PrettyPrinter<'a> {
         ctx: Context,
         math_call: MathCall<'a>,
     ) -> ArenaDoc<'a> {
-        self.convert_verbatim_untyped(math_call.callee().to_untyped())
-            + self.convert_math_args(ctx, math_call.args().to_untyped())
+        let args = self
+            .try_convert_math_matrix(ctx, math_call)
+            .unwrap_or_else(|| self.convert_math_args(ctx, math_call.args().to_untyped()));
+        self.convert_verbatim_untyped(math_call.callee().to_untyped()) + args
     }
 
     pub(super) fn convert_args_of_func(
@@ -60,8 +62,8 @@ impl<'a> PrettyPrinter<'a> {
         func_call: FuncCall<'a>,
         paren_nodes: &'a [SyntaxNode],
     ) -> ArenaDoc<'a> {
-        if table::is_table(func_call) {
-            if let Some(table) = self.try_convert_table(ctx, func_call, paren_nodes) {
+        if let Some(columns_arg) = self.table_columns_arg(func_call) {
+            if let Some(table) = self.try_convert_table(ctx, func_call, columns_arg, paren_nodes) {
                 table
             } else {
                 self.convert_parenthesized_args_as_list(ctx, paren_nodes)
@@ -127,12 +129,16 @@ impl<'a> PrettyPrinter<'a> {
             fold_style => suggest_fold_style_for_args(&pargs, fold_style),
         };
 
+        let paddings = self.named_item_paddings(ctx, paren_nodes, fold_style);
+
         ListStylist::new(self)
             .keep_linebreak(self.config.blank_lines_upper_bound)
             .with_fold_style(fold_style)
             .process_iterable_impl(ctx, paren_nodes.iter(), |ctx, child| {
                 // We should ignore additional args here.
-                child.cast().map(|arg| self.convert_arg(ctx, arg))
+                child
+                    .cast()
+                    .map(|arg| self.convert_padded_arg(ctx, arg, paddings.get(child)))
             })
             .print_doc(ListStyle {
                 ..Default::default()
@@ -11,9 +11,20 @@ use super::{
     prelude::*,
     util::is_comment_node,
 };
+use crate::{AttrStore, WarningKind, ext::StrExt};
 
 impl<'a> PrettyPrinter<'a> {
     pub(super) fn convert_import(&'a self, ctx: Context, import: ModuleImport<'a>) -> ArenaDoc<'a> {
+        self.convert_merged_import(ctx, import, &[])
+    }
+
+    /// Converts an import, merging the items of the given imports of the same source into it.
+    pub(super) fn convert_merged_import(
+        &'a self,
+        ctx: Context,
+        import: ModuleImport<'a>,
+        merged: &[ModuleImport<'a>],
+    ) -> ArenaDoc<'a> {
         // ImportItems are optional and may be wrapped in parentheses.
         let nodes = import.to_untyped().children().as_slice();
 
@@ -68,27 +79,49 @@ impl<'a> PrettyPrinter<'a> {
                 import_items_nodes.push(node);
             }
         }
+        if !merged.is_empty() {
+            import_items_nodes = merge_import_items(import_items_nodes, merged);
+        }
         if import_items_nodes.is_empty() {
             return prefix_doc;
         }
 
-        let import_items_doc = self.convert_import_items(ctx, import_items_nodes);
+        let import_items_doc = self.convert_import_items(ctx, import, import_items_nodes);
         prefix_doc + self.arena.space() + import_items_doc
     }
 
     fn convert_import_items(
         &'a self,
         ctx: Context,
+        import: ModuleImport<'a>,
         mut import_items_nodes: Vec<&'a SyntaxNode>,
     ) -> ArenaDoc<'a> {
+        // Sorting moves the commas, so check for the trailing one in source order.
+        let has_trailing_comma = import_items_nodes
+            .iter()
+            .rfind(|node| !node.kind().is_trivia() && node.kind() != SyntaxKind::RightParen)
+            .is_some_and(|node| node.kind() == SyntaxKind::Comma);
         // Sort import items if the configuration allows it.
-        // The sorting is only applied if all nodes are not comments and if there are no duplicate names.
-        if self.config.reorder_import_items
-            && import_items_nodes.iter().all(|node| !is_comment_node(node))
-            && check_import_name_duplication(&import_items_nodes)
-        {
-            // Sort import items by their text representation.
-            import_items_nodes.sort_by_key(|&node| node.full_text());
+        // The sorting is only applied if there are no duplicate names and every comment can be
+        // attached to an item, so that comments move along with their items.
+        if self.config.reorder_import_items {
+            if !check_import_name_duplication(&import_items_nodes) {
+                self.warn(
+                    import.to_untyped(),
+                    WarningKind::ImportSorting,
+                    "the items bind the same name more than once",
+                );
+            } else if let Some(mut entries) = attach_import_comments(&import_items_nodes) {
+                // Sort import items by their text representation.
+                entries.sort_by_key(|entry| entry.item.full_text());
+                import_items_nodes = entries.into_iter().flat_map(|entry| entry.nodes).collect();
+            } else {
+                self.warn(
+                    import.to_untyped(),
+                    WarningKind::ImportSorting,
+                    "a comment cannot be attached to a single item",
+                );
+            }
         }
         // Note that `ImportItem` does not implement `AstNode`.
         ListStylist::new(self)
@@ -105,6 +138,7 @@ impl<'a> PrettyPrinter<'a> {
                     _ => Option::None,
                 },
             )
+            .with_trailing_sep_in_source(has_trailing_comma)
             .print_doc(ListStyle {
                 omit_delim_flat: true,
                 omit_delim_empty: true,
@@ -166,3 +200,178 @@ fn check_import_name_duplication(import_items_nodes: &[&SyntaxNode]) -> bool {
     }
     true // No duplicates found
 }
+
+/// An import item together with the comments and spaces that move with it when sorting.
+struct ImportEntry<'a> {
+    item: &'a SyntaxNode,
+    /// The leading comments, the item and its trailing comments, in source order.
+    nodes: Vec<&'a SyntaxNode>,
+}
+
+/// Groups import items with their comments.
+///
+/// A comment after an item on the same line trails that item, and other comments lead the next
+/// item. Returns `None` if a comment cannot be attached unambiguously, i.e., it sits between two
+/// items on the same line or follows the last item on its own line.
+fn attach_import_comments<'a>(
+    import_items_nodes: &[&'a SyntaxNode],
+) -> Option<Vec<ImportEntry<'a>>> {
+    let mut entries: Vec<ImportEntry> = vec![];
+    let mut pending = vec![];
+    let mut on_item_line = false;
+    let mut trailing_on_line = false;
+    for &node in import_items_nodes {
+        match node.kind() {
+            SyntaxKind::ImportItemPath | SyntaxKind::RenamedImportItem => {
+                if trailing_on_line {
+                    return None;
+                }
+                pending.push(node);
+                entries.push(ImportEntry {
+                    item: node,
+                    nodes: std::mem::take(&mut pending),
+                });
+                on_item_line = true;
+            }
+            SyntaxKind::LineComment | SyntaxKind::BlockComment => {
+                if on_item_line {
+                    entries.last_mut()?.nodes.push(node);
+                    trailing_on_line = true;
+                } else {
+                    pending.push(node);
+                }
+            }
+            // Line breaks are kept before the leading comments, so that they are not attached to
+            // the previous item after sorting.
+            SyntaxKind::Space if node.leaf_text().has_linebreak() => {
+                on_item_line = false;
+                trailing_on_line = false;
+                pending.push(node);
+            }
+            SyntaxKind::Space if !on_item_line => pending.push(node),
+            // Commas and parentheses are not needed to lay out the items.
+            _ => {}
+        }
+    }
+    if pending.iter().any(|node| is_comment_node(node)) {
+        return None;
+    }
+    Some(entries)
+}
+
+/// Returns whether `next` can be merged into `import` and the imports already merged into it.
+///
+/// Imports can be merged if they import items from the same string source without renaming the
+/// module, and the union of their items binds each name only once.
+pub(super) fn can_merge_imports<'a>(
+    import: ModuleImport<'a>,
+    merged: &[ModuleImport<'a>],
+    next: ModuleImport<'a>,
+    attrs: &AttrStore,
+) -> bool {
+    fn items_source(import: ModuleImport) -> Option<String> {
+        match (import.source(), import.imports()) {
+            (Expr::Str(source), Some(Imports::Items(_))) if import.new_name().is_none() => {
+                Some(source.get().to_string())
+            }
+            _ => None,
+        }
+    }
+
+    if attrs.is_format_disabled(import.to_untyped())
+        || attrs.is_format_disabled(next.to_untyped())
+        || items_source(import).is_none()
+        || items_source(import) != items_source(next)
+    {
+        return false;
+    }
+    let mut others = merged.to_vec();
+    others.push(next);
+    check_import_name_duplication(&merge_import_items(
+        import_item_nodes(import).collect(),
+        &others,
+    ))
+}
+
+/// Returns the flattened nodes of the import items of an import.
+fn import_item_nodes<'a>(import: ModuleImport<'a>) -> impl Iterator<Item = &'a SyntaxNode> {
+    import
+        .to_untyped()
+        .children()
+        .filter_map(|node| node.cast::<ImportItems>())
+        .flat_map(|items| items.to_untyped().children())
+}
+
+/// Appends the import item nodes of the merged imports, skipping items identical to earlier ones.
+fn merge_import_items<'a>(
+    nodes: Vec<&'a SyntaxNode>,
+    merged: &[ModuleImport<'a>],
+) -> Vec<&'a SyntaxNode> {
+    let mut seen = HashSet::new();
+    nodes
+        .into_iter()
+        .chain(merged.iter().flat_map(|&import| import_item_nodes(import)))
+        .filter(|node| !is_import_item(node) || seen.insert(node.full_text()))
+        .collect()
+}
+
+fn is_import_item(node: &SyntaxNode) -> bool {
+    matches!(
+        node.kind(),
+        SyntaxKind::ImportItemPath | SyntaxKind::RenamedImportItem
+    )
+}
+
+/// Returns the key to sort import statements by: packages first, then absolute paths, then
+/// relative paths, each by path and version.
+///
+/// Returns `None` if the source is not a string literal, as such an import may depend on the
+/// bindings of the imports before it.
+pub(super) fn import_sort_key(import: ModuleImport) -> Option<(u8, String, Vec<u64>)> {
+    let Expr::Str(source) = import.source() else {
+        return None;
+    };
+    let source = source.get();
+    Some(if source.starts_with('@') {
+        let (path, version) = source.rsplit_once(':').unwrap_or((source.as_str(), ""));
+        let version = version
+            .split('.')
+            .filter_map(|part| part.parse().ok())
+            .collect();
+        (0, path.into(), version)
+    } else if source.starts_with('/') {
+        (1, source.into(), vec![])
+    } else {
+        (2, source.into(), vec![])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Config, Typstyle, WarningKind};
+
+    #[test]
+    fn warn_when_import_sorting_is_skipped() {
+        let typstyle = Typstyle::new(Config::new().with_reorder_imports(true));
+        let formatter = typstyle.format_text(
+            "#import \"a.typ\": b, /* b or a? */ a\n#import \"b.typ\": x\n#import \"a.typ\": x\n",
+        );
+        formatter.render().unwrap();
+        let warnings = formatter.warnings();
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|w| w.reason.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "the imports bind the same name more than once",
+                "a comment cannot be attached to a single item",
+            ]
+        );
+        assert!(
+            warnings
+                .iter()
+                .all(|w| w.kind == WarningKind::ImportSorting)
+        );
+    }
+}
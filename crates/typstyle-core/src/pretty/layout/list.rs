@@ -1,6 +1,7 @@
 use typst_syntax::{SyntaxKind, SyntaxNode, ast::*};
 
 use crate::{
+    TrailingComma,
     ext::StrExt,
     pretty::{Context, Mode, PrettyPrinter, prelude::*, style::FoldStyle},
 };
@@ -15,6 +16,8 @@ pub struct ListStylist<'a> {
     real_item_count: usize,
     has_comment: bool,
     has_line_comment: bool,
+    /// Whether the last item is followed by a separator in the source.
+    has_trailing_sep: bool,
 
     fold_style: FoldStyle,
     disallow_front_comment: bool,
@@ -92,6 +95,7 @@ impl<'a> ListStylist<'a> {
             real_item_count: 0,
             has_comment: false,
             has_line_comment: false,
+            has_trailing_sep: false,
 
             fold_style: FoldStyle::Fit,
             disallow_front_comment: false,
@@ -118,6 +122,13 @@ impl<'a> ListStylist<'a> {
         self
     }
 
+    /// Overrides whether the last item is followed by a separator in the source.
+    /// Use this when the nodes are not processed in source order.
+    pub fn with_trailing_sep_in_source(mut self, has_trailing_sep: bool) -> Self {
+        self.has_trailing_sep = has_trailing_sep;
+        self
+    }
+
     /// Force to fold if the predicate is true. Has no effect the list contains any comment.
     pub fn always_fold_if(mut self, pred: impl FnOnce() -> bool) -> Self {
         if !self.has_comment && pred() {
@@ -192,6 +203,7 @@ impl<'a> ListStylist<'a> {
         let arena = &self.printer.arena;
 
         self.real_item_count += 1;
+        self.has_trailing_sep = false;
         let before = if self.disallow_front_comment {
             self.detach_comments();
             arena.nil()
@@ -236,6 +248,7 @@ impl<'a> ListStylist<'a> {
                     .push(self.printer.convert_comment(ctx, node));
             }
             SyntaxKind::Comma => {
+                self.has_trailing_sep = true;
                 self.try_attach_comments();
             }
             SyntaxKind::Space => {
@@ -323,6 +336,21 @@ impl<'a> ListStylist<'a> {
             self.fold_style
         };
         let item_count = self.items.len();
+        // The separator after the last item when the list is broken into lines.
+        let trailing_sep = if sty.add_trailing_sep_always
+            || is_single && sty.add_trailing_sep_single
+            || match self.printer.config.trailing_comma {
+                TrailingComma::AlwaysWhenMultiline => true,
+                TrailingComma::Never => false,
+                TrailingComma::Preserve => self.has_trailing_sep,
+            }
+            // Removing a magic trailing comma would collapse the list in the next run.
+            || self.printer.config.magic_trailing_comma && self.has_trailing_sep
+        {
+            sep.clone()
+        } else {
+            arena.nil()
+        };
 
         let enclose_fitted = |inner: ArenaDoc<'a>| {
             if is_single && sty.omit_delim_single {
@@ -359,7 +387,13 @@ impl<'a> ListStylist<'a> {
                     match item {
                         Item::Comment(cmt) => inner += cmt + arena.hardline(),
                         Item::Commented { body, after } => {
-                            inner += body + sep.clone() + after;
+                            seen_real_items += 1;
+                            let follow = if seen_real_items == self.real_item_count {
+                                trailing_sep.clone()
+                            } else {
+                                sep.clone()
+                            };
+                            inner += body + follow + after;
                             if !sty.tight_delim || !is_last {
                                 inner += arena.hardline();
                             }
@@ -435,7 +469,7 @@ impl<'a> ListStylist<'a> {
                     } else {
                         last.clone()
                     };
-                    let expanded = self.printer.block_indent(last + sep.clone());
+                    let expanded = self.printer.block_indent(last + trailing_sep);
 
                     compact.partial_union(expanded)
                 } else {
@@ -449,7 +483,7 @@ impl<'a> ListStylist<'a> {
                             docs.iter()
                                 .map(|doc| doc.clone() + sep.clone() + arena.line()),
                         ) + last
-                            + sep.clone(),
+                            + trailing_sep,
                     );
 
                     compact.partial_union(expanded)
@@ -483,7 +517,11 @@ impl<'a> ListStylist<'a> {
                             seen_real_items += 1;
                             let is_last_real = seen_real_items == self.real_item_count;
                             let follow = if let Some(after) = after {
-                                let follow_break = sep.clone() + after.clone();
+                                let follow_break = if is_last_real {
+                                    trailing_sep.clone()
+                                } else {
+                                    sep.clone()
+                                } + after.clone();
                                 let follow_flat = if !is_last_real
                                     || sty.add_trailing_sep_always
                                     || is_single && sty.add_trailing_sep_single
@@ -503,7 +541,7 @@ impl<'a> ListStylist<'a> {
                                 {
                                     sep.clone()
                                 } else {
-                                    sep.clone().when_group_break()
+                                    trailing_sep.clone().when_group_break()
                                 };
                                 follow
                             };
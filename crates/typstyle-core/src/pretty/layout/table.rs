@@ -1,9 +1,17 @@
-use crate::pretty::prelude::*;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{TableAlign, ext::StrExt, pretty::prelude::*};
 
 pub struct TableCollector<'a> {
     arena: &'a Arena<'a>,
     /// When columns == 0, we will not reflow cells.
     columns: usize,
+    /// Whether to add a comma after the last cell or row.
+    trailing_sep: bool,
+    /// How to pad cells within columns.
+    align: TableAlign,
+    /// The width used to check whether a cell fits on a single line.
+    max_width: usize,
 
     /// The rows of the table. Each row is either a list of cells, a block, or a comment.
     rows: Vec<Row<'a>>,
@@ -13,8 +21,8 @@ pub struct TableCollector<'a> {
 
 enum Row<'a> {
     Cells {
-        /// The combined doc of the cells in this row.
-        doc: ArenaDoc<'a>,
+        /// The cells in this row.
+        cells: Vec<ArenaDoc<'a>>,
         /// Whether an additional line break can be added after this row.
         auto_break: bool,
     },
@@ -25,15 +33,25 @@ enum Row<'a> {
 }
 
 impl<'a> TableCollector<'a> {
-    pub fn new(arena: &'a Arena<'a>, columns: usize) -> Self {
+    pub fn new(arena: &'a Arena<'a>, columns: usize, trailing_sep: bool) -> Self {
         Self {
             columns,
+            trailing_sep,
+            align: TableAlign::None,
+            max_width: 0,
             rows: vec![],
             current_row_cells: Vec::with_capacity(columns.max(2)),
             arena,
         }
     }
 
+    /// Pads cells to align them within columns. Only takes effect when cells are reflowed.
+    pub fn with_align(mut self, align: TableAlign, max_width: usize) -> Self {
+        self.align = align;
+        self.max_width = max_width;
+        self
+    }
+
     pub fn push_cell(&mut self, doc: ArenaDoc<'a>) {
         self.current_row_cells.push(doc);
         if self.current_row_cells.len() == self.columns {
@@ -65,12 +83,9 @@ impl<'a> TableCollector<'a> {
     fn flush_cells(&mut self) {
         if !self.current_row_cells.is_empty() {
             self.rows.push(Row::Cells {
-                doc: self.arena.intersperse(
-                    std::mem::replace(
-                        &mut self.current_row_cells,
-                        Vec::with_capacity(self.columns.max(2)),
-                    ),
-                    self.arena.text(",") + self.arena.line(),
+                cells: std::mem::replace(
+                    &mut self.current_row_cells,
+                    Vec::with_capacity(self.columns.max(2)),
                 ),
                 auto_break: self.columns > 1,
             });
@@ -88,29 +103,97 @@ impl<'a> TableCollector<'a> {
         while matches!(self.rows.last(), Some(Row::Linebreak)) {
             self.rows.pop();
         }
+        let column_widths = self.column_widths();
         let num_rows = self.rows.len();
         let only_one_row = num_rows == 1;
+        let last_arg_row = self
+            .rows
+            .iter()
+            .rposition(|row| matches!(row, Row::Cells { .. } | Row::Block(_)));
+        let sep = |i| {
+            if self.trailing_sep || Some(i) != last_arg_row {
+                self.arena.text(",")
+            } else {
+                self.arena.nil()
+            }
+        };
+        let rows = std::mem::take(&mut self.rows);
         self.arena.intersperse(
-            self.rows.into_iter().enumerate().map(|(i, row)| match row {
-                Row::Cells {
-                    mut doc,
-                    auto_break,
-                } => {
+            rows.into_iter().enumerate().map(|(i, row)| match row {
+                Row::Cells { cells, auto_break } => {
+                    let mut doc = self.join_cells(cells, &column_widths);
                     doc += if only_one_row {
-                        self.arena.text(",").when_group_break()
+                        sep(i).when_group_break()
                     } else {
-                        self.arena.text(",")
+                        sep(i)
                     };
                     if i + 1 < num_rows && auto_break {
                         doc += self.arena.line_()
                     }
                     doc.group()
                 }
-                Row::Block(doc) => doc + self.arena.text(","),
+                Row::Block(doc) => doc + sep(i),
                 Row::Comment(doc) => doc,
                 Row::Linebreak => self.arena.nil(),
             }),
             self.arena.hardline(),
         )
     }
+
+    /// Returns the display width of each column if all its cells are single-line.
+    fn column_widths(&self) -> Vec<Option<usize>> {
+        if self.align == TableAlign::None || self.columns == 0 {
+            return vec![];
+        }
+        let mut widths = vec![Some(0); self.columns];
+        for row in &self.rows {
+            let Row::Cells { cells, .. } = row else {
+                continue;
+            };
+            for (width, cell) in widths.iter_mut().zip(cells) {
+                *width = width.zip(self.cell_width(cell)).map(|(a, b)| a.max(b));
+            }
+        }
+        widths
+    }
+
+    fn cell_width(&self, cell: &ArenaDoc<'a>) -> Option<usize> {
+        let mut buf = String::new();
+        cell.clone().render_fmt(self.max_width, &mut buf).ok()?;
+        (!buf.has_linebreak()).then(|| buf.width())
+    }
+
+    /// Joins the cells of a row, padding them to the widths of their columns.
+    ///
+    /// The padding is dropped if the row breaks, so that broken rows are not indented.
+    fn join_cells(
+        &self,
+        cells: Vec<ArenaDoc<'a>>,
+        column_widths: &[Option<usize>],
+    ) -> ArenaDoc<'a> {
+        let arena = self.arena;
+        if column_widths.is_empty() {
+            return arena.intersperse(cells, arena.text(",") + arena.line());
+        }
+        let num_cells = cells.len();
+        let mut doc = arena.nil();
+        for (i, cell) in cells.into_iter().enumerate() {
+            let padding = column_widths
+                .get(i)
+                .copied()
+                .flatten()
+                .zip(self.cell_width(&cell))
+                .map_or(0, |(column_width, width)| column_width - width);
+            let (before, after) = match self.align {
+                TableAlign::None | TableAlign::Left => (0, padding),
+                TableAlign::Right => (padding, 0),
+                TableAlign::Center => (padding / 2, padding - padding / 2),
+            };
+            doc += arena.spaces(before).when_group_flat() + cell;
+            if i + 1 < num_cells {
+                doc += arena.text(",") + arena.spaces(after).when_group_flat() + arena.line();
+            }
+        }
+        doc
+    }
 }
@@ -1,14 +1,27 @@
 mod wrapping;
 
+use std::{collections::HashSet, iter};
+
 use Option::None;
+use itertools::Itertools;
 use prettyless::Doc;
 use smallvec::SmallVec;
 use typst_syntax::{SyntaxKind, SyntaxNode, ast::*};
+use unicode_width::UnicodeWidthStr;
 
 use super::{
-    Context, Mode, PrettyPrinter, layout::flow::FlowItem, prelude::*, util::is_comment_node,
+    Context, Mode, PrettyPrinter,
+    context::MarkupIndent,
+    import::{can_merge_imports, import_sort_key},
+    layout::flow::FlowItem,
+    prelude::*,
+    symbol::next_sibling,
+    util::is_comment_node,
+};
+use crate::{
+    AttrStore, WarningKind, WrapAlgorithm, WrapMode, ext::StrExt, liteval::import_bindings,
+    pretty::util::is_only_one_and,
 };
-use crate::{WrapMode, ext::StrExt, pretty::util::is_only_one_and};
 
 #[derive(Debug, PartialEq, Eq)]
 enum MarkupScope {
@@ -123,6 +136,7 @@ impl<'a> PrettyPrinter<'a> {
     ) -> ArenaDoc<'a> {
         let node = term_item.to_untyped();
         let mut seen_term = false;
+        let ctx = ctx.with_markup_indent(None);
         let body = self.convert_flow_like(ctx, node, |ctx, child, _| match child.kind() {
             SyntaxKind::TermMarker => FlowItem::spaced(self.arena.text(child.leaf_text().as_str())),
             SyntaxKind::Colon => {
@@ -159,6 +173,17 @@ impl<'a> PrettyPrinter<'a> {
     }
 
     fn convert_list_item_like(&'a self, ctx: Context, item: &'a SyntaxNode) -> ArenaDoc<'a> {
+        // The body starts after the marker, and the following lines are indented.
+        let marker_width = item
+            .children()
+            .next()
+            .map_or(0, |marker| marker.text().width());
+        let ctx = ctx.with_markup_indent((ctx.markup_indent.and_then(MarkupIndent::column)).map(
+            |column| MarkupIndent {
+                first: column + marker_width + 1,
+                rest: column + self.config.tab_spaces,
+            },
+        ));
         let body = self.convert_flow_like(ctx, item, |ctx, child, _| match child.kind() {
             SyntaxKind::ListMarker | SyntaxKind::EnumMarker | SyntaxKind::TermMarker => {
                 FlowItem::spaced(self.arena.text(child.leaf_text().as_str()))
@@ -191,6 +216,12 @@ impl<'a> PrettyPrinter<'a> {
         scope: MarkupScope,
     ) -> ArenaDoc<'a> {
         let ctx = ctx.with_mode(Mode::Markup);
+        // Markup in other scopes may start anywhere on a line.
+        let ctx = if matches!(scope, MarkupScope::Document | MarkupScope::Item) {
+            ctx
+        } else {
+            ctx.with_markup_indent(None)
+        };
 
         // If the markup only contains one space, simply convert it.
         let children = markup.to_untyped().children().as_slice();
@@ -200,15 +231,38 @@ impl<'a> PrettyPrinter<'a> {
             return self.convert_space(ctx, space);
         }
 
-        let repr = collect_markup_repr(markup);
-        let body =
-            if self.config.wrap_mode == WrapMode::Sentence && scope != MarkupScope::InlineItem {
-                self.convert_markup_body_sentence_per_line(ctx, &repr)
-            } else if self.config.wrap_mode != WrapMode::None && scope != MarkupScope::InlineItem {
-                self.convert_markup_body_reflow(ctx, &repr)
-            } else {
-                self.convert_markup_body(ctx, &repr)
-            };
+        let mut repr = collect_markup_repr(markup);
+        if self.config.reorder_imports {
+            for import in reorder_import_lines(&mut repr.lines, &self.attr_store) {
+                self.warn(
+                    import.to_untyped(),
+                    WarningKind::ImportSorting,
+                    "the imports bind the same name more than once",
+                );
+            }
+        }
+        if self.config.merge_imports {
+            merge_import_lines(&mut repr.lines, &self.attr_store);
+        }
+        if self.config.align_assignments {
+            self.align_let_lines(ctx, &mut repr.lines);
+        }
+        let body = if self.config.wrap_mode == WrapMode::Sentence
+            && scope != MarkupScope::InlineItem
+        {
+            self.convert_markup_body_sentence_per_line(ctx, &repr)
+        } else if self.config.wrap_mode == WrapMode::Clause && scope != MarkupScope::InlineItem {
+            self.convert_markup_body_clause(ctx, &repr)
+        } else if self.config.wrap_mode != WrapMode::None && scope != MarkupScope::InlineItem {
+            match ctx.markup_indent {
+                Some(indent) if self.config.wrap_algorithm == WrapAlgorithm::Optimal => {
+                    self.convert_markup_body_optimal(ctx, &repr, indent)
+                }
+                _ => self.convert_markup_body_reflow(ctx, &repr),
+            }
+        } else {
+            self.convert_markup_body(ctx, &repr)
+        };
 
         // Add line or space (if any) to both sides.
         // Only turn space into, not the other way around.
@@ -273,12 +327,13 @@ impl<'a> PrettyPrinter<'a> {
 
     fn convert_markup_body(&'a self, ctx: Context, repr: &MarkupRepr<'a>) -> ArenaDoc<'a> {
         let mut doc = self.arena.nil();
-        for &MarkupLine {
-            ref nodes,
-            breaks,
-            mixed_text,
-        } in repr.lines.iter()
-        {
+        for line in repr.lines.iter() {
+            let &MarkupLine {
+                ref nodes,
+                breaks,
+                mixed_text,
+                ..
+            } = line;
             for node in nodes.iter() {
                 doc += if node.kind() == SyntaxKind::Space {
                     self.convert_space_untyped(ctx, node)
@@ -290,7 +345,7 @@ impl<'a> PrettyPrinter<'a> {
                     } else {
                         ctx
                     };
-                    self.convert_expr(ctx, expr)
+                    self.convert_line_expr(ctx, line, expr)
                 } else if is_comment_node(node) {
                     self.convert_comment(ctx, node)
                 } else {
@@ -304,6 +359,40 @@ impl<'a> PrettyPrinter<'a> {
         }
         doc
     }
+
+    /// Converts an expression on a markup line, merging the imports folded into the line.
+    fn convert_line_expr(
+        &'a self,
+        ctx: Context,
+        line: &MarkupLine<'a>,
+        expr: Expr<'a>,
+    ) -> ArenaDoc<'a> {
+        match expr {
+            Expr::ModuleImport(import) if !line.merged_imports.is_empty() => self
+                .check_disabled(import.to_untyped())
+                .unwrap_or_else(|| self.convert_merged_import(ctx, import, &line.merged_imports)),
+            Expr::Shorthand(shorthand) => self.convert_markup_shorthand(
+                shorthand,
+                next_sibling(&line.nodes, shorthand.to_untyped()),
+            ),
+            _ => self.convert_padded_expr(ctx, expr, line.padding),
+        }
+    }
+
+    /// Pads the `=` of runs of let bindings on consecutive lines to align them.
+    fn align_let_lines(&'a self, ctx: Context, lines: &mut [MarkupLine<'a>]) {
+        let paddings = self.let_binding_paddings(
+            ctx,
+            lines.iter().flat_map(|line| {
+                iter::once(as_let_binding(&line.nodes)).chain((line.breaks != 1).then_some(None))
+            }),
+        );
+        for line in lines {
+            if let Some(binding) = as_let_binding(&line.nodes) {
+                line.padding = paddings.get(binding.to_untyped());
+            }
+        }
+    }
 }
 
 #[derive(Default)]
@@ -311,6 +400,10 @@ struct MarkupLine<'a> {
     nodes: SmallVec<[&'a SyntaxNode; 4]>,
     breaks: usize,
     mixed_text: bool,
+    /// Imports of the same source merged into the import on this line.
+    merged_imports: Vec<ModuleImport<'a>>,
+    /// Padding before the `=` of the let binding on this line.
+    padding: usize,
 }
 
 struct MarkupRepr<'a> {
@@ -465,6 +558,107 @@ fn collect_markup_repr(markup: Markup<'_>) -> MarkupRepr<'_> {
     repr
 }
 
+/// Sorts runs of consecutive import lines that are not separated by blank lines or comments.
+/// Wildcard imports end a run, since the names they bind are unknown, and so do imports with
+/// formatting disabled, which must stay in place.
+///
+/// Returns the first import of each run that was left unsorted.
+fn reorder_import_lines<'a>(
+    lines: &mut [MarkupLine<'a>],
+    attrs: &AttrStore,
+) -> Vec<ModuleImport<'a>> {
+    let mut skipped = vec![];
+    let mut start = 0;
+    while start < lines.len() {
+        let mut end = start;
+        while end < lines.len()
+            && as_import(&lines[end].nodes).is_some_and(|import| {
+                !matches!(import.imports(), Some(Imports::Wildcard))
+                    && !attrs.is_format_disabled(import.to_untyped())
+                    && import_sort_key(import).is_some()
+            })
+        {
+            end += 1;
+            if lines[end - 1].breaks != 1 {
+                break;
+            }
+        }
+        if end - start > 1 && !sort_import_run(&mut lines[start..end]) {
+            skipped.extend(as_import(&lines[start].nodes));
+        }
+        start = end.max(start + 1);
+    }
+    skipped
+}
+
+/// Merges consecutive import lines of the same source into the first one.
+fn merge_import_lines(lines: &mut Vec<MarkupLine>, attrs: &AttrStore) {
+    let mut i = 0;
+    while i + 1 < lines.len() {
+        if lines[i].breaks == 1
+            && let Some(import) = as_import(&lines[i].nodes)
+            && let Some(next) = as_import(&lines[i + 1].nodes)
+            && can_merge_imports(import, &lines[i].merged_imports, next, attrs)
+        {
+            let next_line = lines.remove(i + 1);
+            lines[i].breaks = next_line.breaks;
+            lines[i].merged_imports.push(next);
+        } else {
+            i += 1;
+        }
+    }
+}
+
+/// Sorts a run of import lines, keeping the linebreaks in place.
+///
+/// The run is left untouched if its imports bind the same name, as the order decides which
+/// binding wins. Returns whether the run was sorted.
+fn sort_import_run(run: &mut [MarkupLine]) -> bool {
+    let mut seen = HashSet::new();
+    if !run
+        .iter()
+        .filter_map(|line| as_import(&line.nodes))
+        .flat_map(import_bindings)
+        .all(|name| seen.insert(name))
+    {
+        return false;
+    }
+    let mut sorted = run
+        .iter_mut()
+        .map(|line| std::mem::take(&mut line.nodes))
+        .collect_vec();
+    sorted.sort_by_cached_key(|nodes| as_import(nodes).and_then(import_sort_key));
+    for (line, nodes) in run.iter_mut().zip(sorted) {
+        line.nodes = nodes;
+    }
+    true
+}
+
+/// Returns the import statement if the line consists of exactly one `#import`.
+fn as_import<'a>(nodes: &[&'a SyntaxNode]) -> Option<ModuleImport<'a>> {
+    match nodes {
+        [hash, import] if hash.kind() == SyntaxKind::Hash => import.cast(),
+        _ => None,
+    }
+}
+
+/// Returns the let binding if the line consists of exactly one `#let`, maybe with a line comment.
+fn as_let_binding<'a>(nodes: &[&'a SyntaxNode]) -> Option<LetBinding<'a>> {
+    // A trailing comment does not end a run of aligned bindings.
+    let nodes = match nodes {
+        [rest @ .., comment] if comment.kind() == SyntaxKind::LineComment => rest,
+        _ => nodes,
+    };
+    let nodes = match nodes {
+        [rest @ .., space] if space.kind() == SyntaxKind::Space => rest,
+        _ => nodes,
+    };
+    match nodes {
+        [hash, binding] if hash.kind() == SyntaxKind::Hash => binding.cast(),
+        _ => None,
+    }
+}
+
 /// Returns true if the given markup contains exactly one primary (non-text, non-block) expression,
 /// ignoring spaces, linebreaks, and labels, and no linebreak or parbreak presented.
 fn contains_exactly_one_primary_expr(markup: Markup) -> bool {
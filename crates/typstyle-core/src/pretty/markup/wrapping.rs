@@ -1,9 +1,17 @@
-use icu_segmenter::{SentenceSegmenter, SentenceSegmenterBorrowed};
+use icu_segmenter::{LineSegmenter, SentenceSegmenter, SentenceSegmenterBorrowed};
 use typst_syntax::{SyntaxKind, SyntaxNode, ast::*};
+use unicode_width::UnicodeWidthStr;
 
 use super::{MarkupLine, MarkupRepr};
-use crate::pretty::{
-    Context, PrettyPrinter, prelude::*, text::is_enum_marker, util::is_comment_node,
+use crate::{
+    CjkBreak, Config,
+    pretty::{
+        Context, PrettyPrinter,
+        context::MarkupIndent,
+        prelude::*,
+        text::{is_cjk, is_enum_marker, split_cjk_breaks},
+        util::is_comment_node,
+    },
 };
 
 impl<'a> PrettyPrinter<'a> {
@@ -39,7 +47,7 @@ impl<'a> PrettyPrinter<'a> {
                 } else if let Some(text) = node.cast::<Text>() {
                     self.convert_text_wrapped(text)
                 } else if let Some(expr) = node.cast::<Expr>() {
-                    self.convert_expr(ctx, expr)
+                    self.convert_line_expr(ctx, line, expr)
                 } else if is_comment_node(node) {
                     self.convert_comment(ctx, node)
                 } else {
@@ -56,21 +64,225 @@ impl<'a> PrettyPrinter<'a> {
                 && !reflow_preserve_exclusive(line)
                 && !reflow_preserve_exclusive(&repr.lines[i + 1])
             {
-                doc += self.arena.softline();
+                doc += if self.joins_cjk(line, &repr.lines[i + 1]) {
+                    self.arena.line_().group()
+                } else {
+                    self.arena.softline()
+                };
+            } else if breaks > 0 {
+                doc += self.arena.hardline().repeat(breaks);
+            }
+        }
+        doc
+    }
+
+    /// With optimal filling, the breaks of each paragraph are chosen to minimize the raggedness of
+    /// its lines, rather than filling each line greedily. Breaks are only allowed where reflow
+    /// would allow them, so formatting the output again gives the same breaks.
+    pub(super) fn convert_markup_body_optimal(
+        &'a self,
+        ctx: Context,
+        repr: &MarkupRepr<'a>,
+        indent: MarkupIndent,
+    ) -> ArenaDoc<'a> {
+        let segmenter = (self.config.cjk_break == CjkBreak::Allow)
+            .then(|| LineSegmenter::new_for_non_complex_scripts(Default::default()));
+        let mut pieces = vec![];
+        for (i, line) in repr.lines.iter().enumerate() {
+            let &MarkupLine {
+                ref nodes, breaks, ..
+            } = line;
+            for (j, node) in nodes.iter().enumerate() {
+                if node.kind() == SyntaxKind::Space {
+                    pieces.push(
+                        if nodes
+                            .get(j + 1)
+                            .is_some_and(|node| cannot_break_before_markup(node))
+                        {
+                            Piece::Space
+                        } else if nodes
+                            .get(j + 1)
+                            .is_some_and(|node| reflow_prefers_exclusive(node))
+                            || j > 0 && reflow_prefers_exclusive(nodes[j - 1])
+                        {
+                            Piece::Hard(1)
+                        } else {
+                            Piece::Soft
+                        },
+                    );
+                } else if let Some(text) = node.cast::<Text>() {
+                    let text = text.get();
+                    for (k, word) in text.split_ascii_whitespace().enumerate() {
+                        if k > 0 {
+                            pieces.push(if cannot_break_before_text(word) {
+                                Piece::Space
+                            } else {
+                                Piece::Soft
+                            });
+                        }
+                        let parts = match &segmenter {
+                            None => vec![word],
+                            Some(segmenter) => split_cjk_breaks(segmenter, word),
+                        };
+                        for (l, part) in parts.into_iter().enumerate() {
+                            if l > 0 {
+                                pieces.push(Piece::Joint);
+                            }
+                            pieces.push(Piece::Box(self.arena.text(part), Some(part.width())));
+                        }
+                    }
+                    // special case when a link follows the text
+                    if text.ends_with(' ') {
+                        pieces.push(Piece::Soft);
+                    }
+                } else {
+                    let doc = if let Some(expr) = node.cast::<Expr>() {
+                        self.convert_line_expr(ctx, line, expr)
+                    } else if is_comment_node(node) {
+                        self.convert_comment(ctx, node)
+                    } else {
+                        // can be Hash, Semicolon, Shebang
+                        self.convert_trivia_untyped(node)
+                    };
+                    let width = self.render_single_line(doc.clone()).map(|it| it.width());
+                    pieces.push(Piece::Box(doc, width));
+                }
+            }
+            // Should not eat trailing parbreaks.
+            if breaks == 1
+                && i + 1 != repr.lines.len()
+                && !nodes.last().is_some_and(|last| {
+                    reflow_should_break_after(last) || reflow_preserve_break_after(last)
+                })
+                && !reflow_preserve_exclusive(line)
+                && !reflow_preserve_exclusive(&repr.lines[i + 1])
+            {
+                pieces.push(if self.joins_cjk(line, &repr.lines[i + 1]) {
+                    Piece::Joint
+                } else {
+                    Piece::Soft
+                });
             } else if breaks > 0 {
+                pieces.push(Piece::Hard(breaks));
+            }
+        }
+
+        // Lines after the first hard break start at the indentation of the body.
+        let mut doc = self.arena.nil();
+        let mut column = indent.first;
+        for run in pieces.split_inclusive(|piece| matches!(piece, Piece::Hard(_))) {
+            let (run, breaks) = match run.split_last() {
+                Some((Piece::Hard(breaks), run)) => (run, *breaks),
+                _ => (run, 0),
+            };
+            doc += self.fill_optimal(run, column, indent.rest);
+            if breaks > 0 {
                 doc += self.arena.hardline().repeat(breaks);
+                column = indent.rest;
+            }
+        }
+        doc
+    }
+
+    /// Fills a run of pieces without hard breaks, whose first line starts at `first_column`.
+    /// Falls back to greedy filling if a piece can not be measured on a single line.
+    fn fill_optimal(
+        &'a self,
+        run: &[Piece<'a>],
+        first_column: usize,
+        rest_column: usize,
+    ) -> ArenaDoc<'a> {
+        // Words are separated by soft breaks, and may contain spaces that can not break.
+        // Each word comes with the width of the space before it, if it stays on the line.
+        let mut words: Vec<(ArenaDoc<'a>, Option<usize>, usize)> = vec![];
+        let mut gap = Some(0);
+        for piece in run {
+            let (doc, width) = match piece {
+                Piece::Soft | Piece::Hard(_) => {
+                    gap = Some(1);
+                    continue;
+                }
+                Piece::Joint => {
+                    gap = gap.max(Some(0));
+                    continue;
+                }
+                Piece::Space => (self.arena.space(), Some(1)),
+                Piece::Box(doc, width) => (doc.clone(), *width),
+            };
+            if let (None, Some((last_doc, last_width, _))) = (gap, words.last_mut()) {
+                *last_doc += doc;
+                *last_width = last_width.zip(width).map(|(a, b)| a + b);
+            } else {
+                words.push((doc, width, gap.unwrap_or(0)));
             }
+            gap = None;
+        }
+
+        let separator = |gap: usize, is_break: bool| match (gap, is_break) {
+            (_, true) => self.arena.hardline(),
+            (0, false) => self.arena.nil(),
+            _ => self.arena.space(),
+        };
+        let Some(widths) = words.iter().map(|word| word.1).collect::<Option<Vec<_>>>() else {
+            let mut doc = self.arena.nil();
+            for (i, (word, _, gap)) in words.into_iter().enumerate() {
+                if i > 0 {
+                    doc += if gap == 0 {
+                        self.arena.line_().group()
+                    } else {
+                        self.arena.softline()
+                    };
+                }
+                doc += word;
+            }
+            return doc;
+        };
+        let gaps = words.iter().map(|word| word.2).collect::<Vec<_>>();
+        let max_width = self.config.max_width;
+        let mut breaks = optimal_breaks(
+            &widths,
+            &gaps,
+            max_width.saturating_sub(first_column),
+            max_width.saturating_sub(rest_column),
+        )
+        .into_iter()
+        .peekable();
+
+        let mut doc = self.arena.nil();
+        for (i, (word, _, gap)) in words.into_iter().enumerate() {
+            if i > 0 {
+                doc += separator(gap, breaks.next_if_eq(&i).is_some());
+            }
+            doc += word;
         }
         doc
     }
 
+    /// Whether the line break between two lines falls between CJK characters, and may be
+    /// removed when breaking between CJK characters is allowed.
+    fn joins_cjk(&self, line: &MarkupLine, next: &MarkupLine) -> bool {
+        self.config.cjk_break == CjkBreak::Allow
+            && line
+                .nodes
+                .last()
+                .and_then(|node| node.cast::<Text>())
+                .and_then(|text| text.get().chars().next_back())
+                .is_some_and(is_cjk)
+            && next
+                .nodes
+                .first()
+                .and_then(|node| node.cast::<Text>())
+                .and_then(|text| text.get().chars().next())
+                .is_some_and(is_cjk)
+    }
+
     /// With sentence-per-line mode, split sentence boundaries inside text leaves.
     pub(super) fn convert_markup_body_sentence_per_line(
         &'a self,
         ctx: Context,
         repr: &MarkupRepr<'a>,
     ) -> ArenaDoc<'a> {
-        let segmenter = SentenceSegmenter::new(Default::default());
+        let sentences = Sentences::new(&self.config);
 
         let mut doc = self.arena.nil();
         let mut pending_sentence_break = false;
@@ -93,7 +305,7 @@ impl<'a> PrettyPrinter<'a> {
                     }
                 } else if let Some(text) = node.cast::<Text>() {
                     let (text_doc, ended_sentence) =
-                        convert_text_sentence_per_line(&self.arena, &segmenter, text);
+                        convert_text_sentence_per_line(&self.arena, &sentences, text);
                     let leading_break = if pending_sentence_break {
                         self.arena.hardline()
                     } else {
@@ -109,8 +321,8 @@ impl<'a> PrettyPrinter<'a> {
                     } else {
                         self.arena.nil()
                     };
-                    pending_sentence_break = inline_node_ends_with_sentence(node);
-                    leading_break + self.convert_expr(ctx, expr)
+                    pending_sentence_break = sentences.inline_node_ends_sentence(node);
+                    leading_break + self.convert_line_expr(ctx, line, expr)
                 } else if is_comment_node(node) {
                     pending_sentence_break = false;
                     self.convert_comment(ctx, node)
@@ -120,7 +332,7 @@ impl<'a> PrettyPrinter<'a> {
                     } else {
                         self.arena.nil()
                     };
-                    pending_sentence_break = inline_node_ends_with_sentence(node);
+                    pending_sentence_break = sentences.inline_node_ends_sentence(node);
                     leading_break + self.convert_trivia_untyped(node)
                 };
             }
@@ -132,17 +344,267 @@ impl<'a> PrettyPrinter<'a> {
 
         doc
     }
+
+    /// With clause mode, put each sentence on its own line. Long sentences break after clause
+    /// punctuation first, and words are filled within clauses.
+    pub(super) fn convert_markup_body_clause(
+        &'a self,
+        ctx: Context,
+        repr: &MarkupRepr<'a>,
+    ) -> ArenaDoc<'a> {
+        let sentences = Sentences::new(&self.config);
+
+        let mut clauses = ClauseWriter::new(&self.arena);
+        for (i, line) in repr.lines.iter().enumerate() {
+            let &MarkupLine {
+                ref nodes, breaks, ..
+            } = line;
+            for (j, node) in nodes.iter().enumerate() {
+                if node.kind() == SyntaxKind::Space {
+                    if nodes
+                        .get(j + 1)
+                        .is_some_and(|node| reflow_prefers_exclusive(node))
+                        || j > 0 && reflow_prefers_exclusive(nodes[j - 1])
+                    {
+                        clauses.hardlines(1);
+                    } else {
+                        clauses.space(
+                            !nodes
+                                .get(j + 1)
+                                .is_some_and(|node| cannot_break_before_markup(node)),
+                        );
+                    }
+                } else if let Some(text) = node.cast::<Text>() {
+                    clauses.text(&sentences, text.get());
+                } else if is_sentence_closer(node) {
+                    clauses.push_closer(self.convert_trivia_untyped(node));
+                } else if let Some(expr) = node.cast::<Expr>() {
+                    clauses.push(
+                        self.convert_line_expr(ctx, line, expr),
+                        inline_node_ending(&sentences, node),
+                    );
+                } else if is_comment_node(node) {
+                    clauses.push(self.convert_comment(ctx, node), Ending::Word);
+                } else {
+                    // can be Hash, Semicolon, Shebang
+                    clauses.push(self.convert_trivia_untyped(node), Ending::Word);
+                }
+            }
+            // Join lines as in fill mode, but keep the trailing parbreaks.
+            if breaks == 1
+                && i + 1 != repr.lines.len()
+                && !nodes.last().is_some_and(|last| {
+                    reflow_should_break_after(last) || reflow_preserve_break_after(last)
+                })
+                && !reflow_preserve_exclusive(line)
+                && !reflow_preserve_exclusive(&repr.lines[i + 1])
+            {
+                clauses.space(true);
+            } else if breaks > 0 {
+                clauses.hardlines(breaks);
+            }
+        }
+        clauses.finish()
+    }
+}
+
+/// What a word or node ends, deciding the break after it in clause mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Ending {
+    Word,
+    Clause,
+    Sentence,
+}
+
+/// Builds the doc of clause mode, grouping each clause with the break before it, so that a
+/// clause which does not fit on the current line starts a new one.
+struct ClauseWriter<'a> {
+    arena: &'a Arena<'a>,
+    doc: ArenaDoc<'a>,
+    /// The break before the current clause.
+    sep: ArenaDoc<'a>,
+    clause: ArenaDoc<'a>,
+    ending: Ending,
+}
+
+impl<'a> ClauseWriter<'a> {
+    fn new(arena: &'a Arena<'a>) -> Self {
+        Self {
+            arena,
+            doc: arena.nil(),
+            sep: arena.nil(),
+            clause: arena.nil(),
+            ending: Ending::Word,
+        }
+    }
+
+    fn push(&mut self, doc: ArenaDoc<'a>, ending: Ending) {
+        self.clause += doc;
+        self.ending = ending;
+    }
+
+    /// Pushes a closing quote, which belongs to the preceding word.
+    fn push_closer(&mut self, doc: ArenaDoc<'a>) {
+        self.clause += doc;
+    }
+
+    /// Adds the whitespace between two words, which can only break if `can_break`.
+    fn space(&mut self, can_break: bool) {
+        let ending = std::mem::replace(&mut self.ending, Ending::Word);
+        if !can_break {
+            self.clause += self.arena.space();
+            return;
+        }
+        match ending {
+            Ending::Sentence => self.hardlines(1),
+            Ending::Clause => {
+                self.flush();
+                self.sep = self.arena.line();
+            }
+            Ending::Word => self.clause += self.arena.softline(),
+        }
+    }
+
+    fn hardlines(&mut self, count: usize) {
+        self.flush();
+        self.doc += self.arena.hardline().repeat(count);
+        self.sep = self.arena.nil();
+        self.ending = Ending::Word;
+    }
+
+    fn flush(&mut self) {
+        let sep = std::mem::replace(&mut self.sep, self.arena.nil());
+        let clause = std::mem::replace(&mut self.clause, self.arena.nil());
+        self.doc += (sep + clause).group();
+    }
+
+    fn finish(mut self) -> ArenaDoc<'a> {
+        self.flush();
+        self.doc
+    }
+
+    /// Pushes the words of a text leaf, splitting it into sentences and clauses.
+    fn text(&mut self, sentences: &Sentences, text: &'a str) {
+        let mut boundaries = sentences.segmenter.segment_str(text);
+        let Some(mut start) = boundaries.next() else {
+            return;
+        };
+        let mut first = true;
+        for end in boundaries {
+            let sentence = text[start..end].trim();
+            start = end;
+            let mut words = sentence.split_ascii_whitespace().peekable();
+            while let Some(word) = words.next() {
+                if !first {
+                    self.space(!cannot_break_before_text(word));
+                }
+                first = false;
+                let ending = if words.peek().is_some() {
+                    word_ending(word)
+                } else if sentences.ends_sentence(sentence) {
+                    Ending::Sentence
+                } else {
+                    word_ending(word)
+                };
+                self.push(self.arena.text(word), ending);
+            }
+        }
+        if text.ends_with(' ') {
+            self.space(false);
+        }
+    }
+}
+
+/// Whether the word ends a clause, such as with a comma or an em dash.
+fn word_ending(word: &str) -> Ending {
+    if trim_sentence_closers(word).ends_with([',', ';', ':', '—']) {
+        Ending::Clause
+    } else {
+        Ending::Word
+    }
+}
+
+/// Inline markup wrappers end with the punctuation of their content, and `---` is an em dash.
+fn inline_node_ending(sentences: &Sentences, node: &SyntaxNode) -> Ending {
+    if sentences.inline_node_ends_sentence(node) {
+        Ending::Sentence
+    } else if node.kind() == SyntaxKind::Shorthand && node.text().as_str() == "---"
+        || (node.is::<Strong>() || node.is::<Emph>() || node.is::<Raw>() || node.is::<Equation>())
+            && word_ending(&node.full_text()) == Ending::Clause
+    {
+        Ending::Clause
+    } else {
+        Ending::Word
+    }
+}
+
+/// A piece of a paragraph in optimal filling.
+enum Piece<'a> {
+    /// An unbreakable word or node, with its width if it fits on a single line.
+    Box(ArenaDoc<'a>, Option<usize>),
+    /// A space that can not break.
+    Space,
+    /// A space or line break that may become either.
+    Soft,
+    /// A line break between CJK characters that may be removed.
+    Joint,
+    /// Line breaks that are kept.
+    Hard(usize),
+}
+
+/// Returns the indices of the words that start a new line, minimizing the sum of the squared
+/// slack of all lines but the last. Each word is preceded by a gap of the given width unless it
+/// starts a line. The first line has `first_avail` columns, and the others have `rest_avail`.
+/// A word wider than its line is placed on a line of its own.
+fn optimal_breaks(
+    widths: &[usize],
+    gaps: &[usize],
+    first_avail: usize,
+    rest_avail: usize,
+) -> Vec<usize> {
+    let n = widths.len();
+    // cost[i] is the minimal cost of the words from i on, starting a line at word i,
+    // and next[i] is the start of the line after it.
+    let mut cost = vec![0u64; n + 1];
+    let mut next = vec![n; n + 1];
+    for i in (0..n).rev() {
+        let avail = if i == 0 { first_avail } else { rest_avail };
+        cost[i] = u64::MAX;
+        let mut len = 0;
+        for (k, &width) in widths.iter().enumerate().skip(i) {
+            len += width + if k > i { gaps[k] } else { 0 };
+            if k > i && len > avail {
+                break;
+            }
+            let slack = avail.saturating_sub(len) as u64;
+            let line_cost = if k + 1 == n { 0 } else { slack * slack };
+            let total = line_cost.saturating_add(cost[k + 1]);
+            // Prefer longer lines on ties.
+            if total <= cost[i] {
+                cost[i] = total;
+                next[i] = k + 1;
+            }
+        }
+    }
+
+    let mut breaks = vec![];
+    let mut i = next[0];
+    while i < n {
+        breaks.push(i);
+        i = next[i];
+    }
+    breaks
 }
 
 // Text conversion helper.
 
 fn convert_text_sentence_per_line<'a>(
     arena: &'a Arena<'a>,
-    segmenter: &SentenceSegmenterBorrowed,
+    sentences: &Sentences,
     text: Text<'a>,
 ) -> (ArenaDoc<'a>, bool) {
     let text = text.get();
-    let mut boundaries = segmenter.segment_str(text);
+    let mut boundaries = sentences.segmenter.segment_str(text);
     let Some(mut start) = boundaries.next() else {
         return (arena.nil(), false);
     };
@@ -166,8 +628,8 @@ fn convert_text_sentence_per_line<'a>(
                 doc += arena.space();
             }
             first = false;
-            previous_was_abbreviation = is_common_abbreviation(sentence);
-            ended_sentence = source_ends_with_sentence(sentence);
+            previous_was_abbreviation = sentences.ends_with_abbreviation(sentence);
+            ended_sentence = sentences.ends_sentence(sentence);
         }
         start = end;
     }
@@ -244,14 +706,6 @@ fn cannot_break_before_markup(node: &SyntaxNode) -> bool {
         || is_enum_marker(text)
 }
 
-/// Only inline markup wrappers contribute their terminal punctuation to the surrounding
-/// sentence. Arbitrary expressions and content blocks can end with punctuation while still being
-/// followed by prose in the same sentence.
-fn inline_node_ends_with_sentence(node: &SyntaxNode) -> bool {
-    (node.is::<Strong>() || node.is::<Emph>() || node.is::<Raw>() || node.is::<Equation>())
-        && source_ends_with_sentence(&node.full_text())
-}
-
 /// Do not introduce a line break before text that becomes a markup marker at the start of a line.
 fn cannot_break_before_text(text: &str) -> bool {
     let Some(first) = text.split_ascii_whitespace().next() else {
@@ -264,10 +718,6 @@ fn is_line_sensitive_markup_marker(text: &str) -> bool {
     matches!(text, "+" | "-" | "/") || !text.is_empty() && text.chars().all(|c| c == '=')
 }
 
-fn source_ends_with_sentence(text: &str) -> bool {
-    sentence_ends_with_punctuation(text) && !ends_with_common_abbreviation(text)
-}
-
 fn sentence_ends_with_punctuation(text: &str) -> bool {
     trim_sentence_closers(text).ends_with(['.', '!', '?', '。', '！', '？'])
 }
@@ -299,43 +749,88 @@ fn trim_sentence_closers(text: &str) -> &str {
     })
 }
 
-fn ends_with_common_abbreviation(text: &str) -> bool {
-    trim_sentence_closers(text)
-        .split_ascii_whitespace()
-        .next_back()
-        .is_some_and(is_common_abbreviation)
+/// Finds the sentences of text, where a period after an abbreviation or a single capital
+/// initial does not end a sentence.
+struct Sentences<'c> {
+    segmenter: SentenceSegmenterBorrowed<'static>,
+    abbreviations: &'static [&'static str],
+    custom_abbreviations: &'c [String],
 }
 
-fn is_common_abbreviation(text: &str) -> bool {
-    matches!(
-        text.to_ascii_lowercase().as_str(),
-        "dr."
-            | "mr."
-            | "mrs."
-            | "ms."
-            | "prof."
-            | "sr."
-            | "jr."
-            | "st."
-            | "vs."
-            | "etc."
-            | "e.g."
-            | "i.e."
-            | "al."
-            | "vol."
-            | "ed."
-            | "pp."
-            | "fig."
-            | "sec."
-            | "approx."
-    )
+impl<'c> Sentences<'c> {
+    fn new(config: &'c Config) -> Self {
+        Self {
+            segmenter: SentenceSegmenter::new(Default::default()),
+            abbreviations: builtin_abbreviations(&config.sentence_locale),
+            custom_abbreviations: &config.sentence_abbreviations,
+        }
+    }
+
+    /// Whether the text ends with sentence punctuation that is not the period of an abbreviation.
+    fn ends_sentence(&self, text: &str) -> bool {
+        sentence_ends_with_punctuation(text) && !self.ends_with_abbreviation(text)
+    }
+
+    /// Only inline markup wrappers contribute their terminal punctuation to the surrounding
+    /// sentence. Arbitrary expressions and content blocks can end with punctuation while still
+    /// being followed by prose in the same sentence.
+    fn inline_node_ends_sentence(&self, node: &SyntaxNode) -> bool {
+        (node.is::<Strong>() || node.is::<Emph>() || node.is::<Raw>() || node.is::<Equation>())
+            && self.ends_sentence(&node.full_text())
+    }
+
+    fn ends_with_abbreviation(&self, text: &str) -> bool {
+        trim_sentence_closers(text)
+            .split_ascii_whitespace()
+            .next_back()
+            .is_some_and(|word| self.is_abbreviation(word))
+    }
+
+    fn is_abbreviation(&self, word: &str) -> bool {
+        let word = word.trim_start_matches(|c: char| !c.is_alphanumeric());
+        let Some(stem) = word.strip_suffix('.') else {
+            return false;
+        };
+        // A single capital initial, such as in "J. Smith".
+        let mut chars = stem.chars();
+        if chars.next().is_some_and(char::is_uppercase) && chars.next().is_none() {
+            return true;
+        }
+        let word = word.to_lowercase();
+        let stem = stem.to_lowercase();
+        self.abbreviations.contains(&word.as_str())
+            || self
+                .custom_abbreviations
+                .iter()
+                .any(|abbr| abbr.trim_end_matches('.').to_lowercase() == stem)
+    }
+}
+
+/// Returns the abbreviations of the language of the locale, falling back to English.
+fn builtin_abbreviations(locale: &str) -> &'static [&'static str] {
+    let language = locale.split(['-', '_']).next().unwrap_or_default();
+    match language.to_ascii_lowercase().as_str() {
+        "de" => &[
+            "abb.", "bspw.", "bzw.", "ca.", "d.h.", "dr.", "evtl.", "ggf.", "hrsg.", "inkl.",
+            "nr.", "prof.", "s.", "sog.", "u.a.", "usw.", "vgl.", "z.b.",
+        ],
+        "fr" => &[
+            "chap.", "cf.", "dr.", "env.", "etc.", "fig.", "m.", "mlle.", "mme.", "p.", "pr.",
+            "vol.",
+        ],
+        _ => &[
+            "al.", "approx.", "cf.", "ch.", "dr.", "e.g.", "ed.", "eq.", "eqs.", "etc.", "fig.",
+            "figs.", "i.e.", "jr.", "mr.", "mrs.", "ms.", "pp.", "prof.", "resp.", "sec.", "sr.",
+            "st.", "viz.", "vol.", "vs.",
+        ],
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use typst_syntax::Source;
 
-    use crate::{Config, Typstyle, WrapMode};
+    use crate::{CjkBreak, Config, Typstyle, WrapAlgorithm, WrapMode};
 
     fn format_markup(input: &str, config: Config) -> String {
         Typstyle::new(config)
@@ -403,6 +898,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sentence_mode_keeps_abbreviations_and_initials() {
+        assert_eq!(
+            format_sentences("Dr. Smith and J. Doe agree. Next."),
+            "Dr. Smith and J. Doe agree.\nNext.\n"
+        );
+
+        let input = "By Prop. Two the claim follows. Next.";
+        assert_eq!(
+            format_sentences(input),
+            "By Prop.\nTwo the claim follows.\nNext.\n"
+        );
+        let config = Config::new()
+            .with_wrap_mode(WrapMode::Sentence)
+            .with_sentence_abbreviations(vec!["Prop".to_string()]);
+        assert_eq!(
+            format_markup(input, config),
+            "By Prop. Two the claim follows.\nNext.\n"
+        );
+    }
+
+    #[test]
+    fn sentence_mode_uses_abbreviations_of_locale() {
+        let input = "Siehe vgl. Abb. 2 dazu. Weiter.";
+        assert_eq!(
+            format_sentences(input),
+            "Siehe vgl.\nAbb. 2 dazu.\nWeiter.\n"
+        );
+        let config = Config::new()
+            .with_wrap_mode(WrapMode::Sentence)
+            .with_sentence_locale("de-DE".to_string());
+        assert_eq!(
+            format_markup(input, config),
+            "Siehe vgl. Abb. 2 dazu.\nWeiter.\n"
+        );
+    }
+
     #[test]
     fn sentence_mode_keeps_closing_quotes_with_the_sentence() {
         assert_eq!(
@@ -415,4 +947,63 @@ mod tests {
     fn sentence_mode_carries_breaks_across_non_text_nodes() {
         assert_eq!(format_sentences("A.#foo Next."), "A.\n#foo Next.\n");
     }
+
+    #[test]
+    fn clause_mode_breaks_long_sentences_at_clauses() {
+        let config = Config::new()
+            .with_width(20)
+            .with_wrap_mode(WrapMode::Clause);
+        assert_eq!(
+            format_markup("One, two three four five, six. Next.", config.clone()),
+            "One,\ntwo three four five,\nsix.\nNext.\n"
+        );
+        // Sentences that fit are kept on one line.
+        assert_eq!(format_markup("A, b.\nC", config), "A, b.\nC\n");
+    }
+
+    #[test]
+    fn optimal_fill_evens_out_lines() {
+        let input = "Optimal filling keeps the lines of a paragraph at similar lengths, \
+            so that `inline code` and $x + y$ do not leave a ragged edge behind them.";
+        let config = Config::new()
+            .with_wrap_mode(WrapMode::Fill)
+            .with_wrap_algorithm(WrapAlgorithm::Optimal);
+
+        let expected = "Optimal filling keeps the lines of\n\
+            a paragraph at similar lengths, so\n\
+            that `inline code` and $x + y$ do\n\
+            not leave a ragged edge behind them.\n";
+        let output = format_markup(input, config.clone().with_width(36));
+        assert_eq!(output, expected);
+        assert_eq!(
+            format_markup(&output, config.clone().with_width(36)),
+            expected
+        );
+
+        // The body of a list item starts after the marker.
+        let expected = "- Optimal filling keeps the lines of\n  \
+            a paragraph at similar lengths, so\n  \
+            that `inline code` and $x + y$ do\n  \
+            not leave a ragged edge behind them.\n";
+        let output = format_markup(&format!("- {input}"), config.clone().with_width(38));
+        assert_eq!(output, expected);
+        assert_eq!(format_markup(&output, config.with_width(38)), expected);
+    }
+
+    #[test]
+    fn cjk_break_wraps_between_cjk_characters() {
+        let input = "中文段落需要在汉字之间换行。";
+        let config = Config::new().with_width(10).with_wrap_mode(WrapMode::Fill);
+        assert_eq!(format_markup(input, config.clone()), format!("{input}\n"));
+
+        let config = config.with_cjk_break(CjkBreak::Allow);
+        let expected = "中文段落需\n要在汉字之\n间换行。\n";
+        assert_eq!(format_markup(input, config.clone()), expected);
+        assert_eq!(format_markup(expected, config.clone()), expected);
+        // Lines breaking between CJK characters are joined without a space.
+        assert_eq!(
+            format_markup("中文\n段落", config.with_width(80)),
+            "中文段落\n"
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use itertools::Itertools;
 use typst_syntax::{SyntaxKind, SyntaxNode, ast::*};
 
 use super::{
@@ -26,9 +27,8 @@ impl<'a> PrettyPrinter<'a> {
                 })
         }
 
-        let ctx = ctx.with_mode(Mode::Math);
-
         let is_block = equation.block();
+        let ctx = ctx.with_mode(Mode::Math).in_equation(is_block);
 
         let convert_math_padded = |ctx: Context, child: &'a SyntaxNode| {
             let math = child.cast::<Math>()?;
@@ -40,7 +40,11 @@ impl<'a> PrettyPrinter<'a> {
             } else {
                 ctx.aligned(AlignMode::Never)
             };
-            let body = self.convert_math(ctx, math);
+            let body = if is_block && let Some(body) = self.try_convert_math_broken(ctx, math) {
+                body
+            } else {
+                self.convert_math(ctx, math)
+            };
             let body = if !is_block && has_trailing_linebreak(equation) {
                 body + self.arena.space()
             } else {
@@ -79,22 +83,100 @@ impl<'a> PrettyPrinter<'a> {
         self.convert_math_children(ctx, math.to_untyped().children())
     }
 
+    /// Break a long block equation before top-level relation operators, and then before `+` and
+    /// `-`, with hanging indentation. The breaks only replace spaces, which math mode ignores.
+    ///
+    /// Linebreaks already at these positions are laid out again, so the output is stable.
+    fn try_convert_math_broken(&'a self, ctx: Context, math: Math<'a>) -> Option<ArenaDoc<'a>> {
+        if !self.config.break_long_equations
+            || ctx.break_suppressed
+            || self.attr_store.is_format_disabled(math.to_untyped())
+            || self.attr_store.has_math_align_point(math.to_untyped())
+        {
+            return None;
+        }
+        let children = math.to_untyped().children().as_slice();
+        // Only break at spaces around binary operators.
+        let breaks = (1..children.len().saturating_sub(2))
+            .filter_map(|i| {
+                let level = operator_level(&children[i + 1])
+                    .filter(|level| *level != OperatorLevel::Multiplicative)?;
+                (children[i].kind() == SyntaxKind::Space
+                    && children[i + 2].kind() == SyntaxKind::Space
+                    && operator_level(&children[i - 1]).is_none())
+                .then_some((i, level))
+            })
+            .collect_vec();
+        // Respect the linebreaks written by the author elsewhere.
+        if breaks.is_empty()
+            || children.iter().enumerate().any(|(i, child)| {
+                matches!(child.kind(), SyntaxKind::Linebreak)
+                    || is_comment_node(child)
+                    || child.kind() == SyntaxKind::Space
+                        && child.leaf_text().has_linebreak()
+                        && !breaks.iter().any(|(j, _)| *j == i)
+            })
+        {
+            return None;
+        }
+
+        let ctx = ctx.suppress_breaks();
+        let mut relations = vec![vec![]];
+        let mut start = 0;
+        for (i, level) in breaks {
+            let part = self.convert_math_children(ctx, children[start..i].iter());
+            relations.last_mut()?.push(part);
+            if level == OperatorLevel::Relation {
+                relations.push(vec![]);
+            }
+            start = i + 1;
+        }
+        let part = self.convert_math_children(ctx, children[start..].iter());
+        relations.last_mut()?.push(part);
+
+        let hang = |parts: Vec<ArenaDoc<'a>>| {
+            let mut parts = parts.into_iter();
+            let first = parts.next().unwrap_or_else(|| self.arena.nil());
+            let rest = self
+                .arena
+                .concat(parts.map(|part| self.arena.line() + part));
+            (first + self.indent(rest)).group()
+        };
+        Some(hang(relations.into_iter().map(hang).collect()))
+    }
+
     pub(super) fn convert_math_children(
         &'a self,
         ctx: Context,
         math_children: impl Iterator<Item = &'a SyntaxNode>,
     ) -> ArenaDoc<'a> {
+        let children = math_children.collect_vec();
+        let math_spacing = self.config.math_spacing;
         let mut doc = self.arena.nil();
         let mut peek_hash = false;
-        for node in math_children {
+        let mut padded = false;
+        for (i, &node) in children.iter().enumerate() {
             let at_hash = peek_hash;
             peek_hash = false;
-            if let Some(expr) = node.cast::<Expr>() {
+            let spaced = math_spacing && is_binary_operator(&children, i);
+            let respelling = self.respell_math_symbol(&children, i);
+            let pad_before = spaced || respelling.as_ref().is_some_and(|it| it.pad_before);
+            let pad_after = spaced || respelling.as_ref().is_some_and(|it| it.pad_after);
+            if pad_before && !padded && i > 0 && children[i - 1].kind() != SyntaxKind::Space {
+                doc += self.arena.space();
+            }
+            if let Some(respelling) = respelling {
+                doc += self.arena.text(respelling.text);
+            } else if let Some(frac) = self.try_convert_frac_style(ctx, &children, i) {
+                doc += frac;
+            } else if let Some(expr) = node.cast::<Expr>() {
                 let ctx = ctx.with_mode_if(Mode::Code, at_hash);
                 let expr_doc = self.convert_expr(ctx, expr);
                 doc += expr_doc;
             } else if let Some(space) = node.cast::<Space>() {
-                doc += self.convert_space(ctx, space);
+                if !(math_spacing && is_space_before_primes(&children, i)) {
+                    doc += self.convert_space(ctx, space);
+                }
             } else if node.kind() == SyntaxKind::Hash {
                 doc += self.arena.text("#");
                 peek_hash = true;
@@ -104,6 +186,13 @@ impl<'a> PrettyPrinter<'a> {
                 // may be LeftParen, RightParen
                 doc += self.convert_trivia_untyped(node);
             }
+            padded = pad_after
+                && children
+                    .get(i + 1)
+                    .is_some_and(|next| next.kind() != SyntaxKind::Space);
+            if padded {
+                doc += self.arena.space();
+            }
         }
         doc
     }
@@ -124,6 +213,8 @@ impl<'a> PrettyPrinter<'a> {
                 if first.leaf_text().has_linebreak() {
                     has_open_linebreak = true;
                     self.arena.hardline()
+                } else if self.config.math_spacing {
+                    self.arena.nil()
                 } else {
                     self.arena.space()
                 }
@@ -137,7 +228,11 @@ impl<'a> PrettyPrinter<'a> {
             if last.kind() == SyntaxKind::Space {
                 has_close_space = true;
                 inner_nodes = rest;
-                self.convert_space_untyped(ctx, last)
+                if self.config.math_spacing && !last.leaf_text().has_linebreak() {
+                    self.arena.nil()
+                } else {
+                    self.convert_space_untyped(ctx, last)
+                }
             } else {
                 self.arena.nil()
             }
@@ -231,3 +326,148 @@ impl<'a> PrettyPrinter<'a> {
         })
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum OperatorLevel {
+    /// Relations such as `=`, `<=` and `arrow`, where long equations break first.
+    Relation,
+    /// `+` and `-`, where long sides of relations break.
+    Additive,
+    /// Operators such as `*`, `times` and `dot`, where long equations do not break.
+    Multiplicative,
+}
+
+/// Returns the level of a binary math operator.
+pub(super) fn operator_level(node: &SyntaxNode) -> Option<OperatorLevel> {
+    const RELATIONS: &[&str] = &[
+        "=",
+        "<",
+        ">",
+        "!=",
+        "<=",
+        ">=",
+        "~",
+        ":=",
+        "::=",
+        "=:",
+        "->",
+        "<-",
+        "<->",
+        "=>",
+        "<=>",
+        "==>",
+        "<==",
+        "<==>",
+        "-->",
+        "<--",
+        "<-->",
+        "|->",
+        "eq",
+        "eq.not",
+        "lt",
+        "lt.eq",
+        "gt",
+        "gt.eq",
+        "approx",
+        "equiv",
+        "prop",
+        "tilde.op",
+        "in",
+        "in.not",
+        "subset",
+        "subset.eq",
+        "supset",
+        "supset.eq",
+        "arrow",
+        "arrow.r",
+        "arrow.l",
+        "arrow.l.r",
+        "arrow.double",
+        "arrow.r.double",
+        "arrow.l.double",
+        "arrow.l.r.double",
+        "arrow.long",
+        "arrow.r.long",
+        "arrow.r.bar",
+        "arrow.bar",
+    ];
+    const ADDITIVES: &[&str] = &["+", "-", "plus", "minus", "plus.minus", "minus.plus"];
+    const MULTIPLICATIVES: &[&str] = &[
+        "*", "times", "dot", "dot.op", "ast.op", "div", "and", "or", "union", "sect",
+    ];
+
+    if !matches!(
+        node.kind(),
+        SyntaxKind::MathText
+            | SyntaxKind::MathShorthand
+            | SyntaxKind::MathIdent
+            | SyntaxKind::MathFieldAccess
+    ) {
+        return None;
+    }
+    let text = node.clone().into_text();
+    if RELATIONS.contains(&text.as_str()) {
+        Some(OperatorLevel::Relation)
+    } else if ADDITIVES.contains(&text.as_str()) {
+        Some(OperatorLevel::Additive)
+    } else if MULTIPLICATIVES.contains(&text.as_str()) {
+        Some(OperatorLevel::Multiplicative)
+    } else {
+        None
+    }
+}
+
+/// Whether the node at `i` is an operator between two operands, ignoring spaces.
+///
+/// Separators and opening delimiters are not operands, so the sign in `(a, -b)` stays unary.
+fn is_binary_operator(children: &[&SyntaxNode], i: usize) -> bool {
+    let is_operand = |node: &SyntaxNode| {
+        operator_level(node).is_none()
+            && !is_comment_node(node)
+            && !matches!(
+                node.kind(),
+                SyntaxKind::Linebreak
+                    | SyntaxKind::MathAlignPoint
+                    | SyntaxKind::Hash
+                    | SyntaxKind::Comma
+                    | SyntaxKind::Semicolon
+            )
+            && !matches!(node.text().as_str(), "," | ";")
+    };
+    let is_opening = |node: &SyntaxNode| {
+        matches!(
+            node.kind(),
+            SyntaxKind::LeftParen | SyntaxKind::LeftBracket | SyntaxKind::LeftBrace
+        ) || matches!(node.text().as_str(), "(" | "[" | "{" | "[|")
+    };
+    let mut prev = children[..i].iter().rev();
+    let mut next = children[i + 1..].iter();
+    operator_level(children[i]).is_some()
+        && prev
+            .find(|node| node.kind() != SyntaxKind::Space)
+            .is_some_and(|node| is_operand(node) && !is_opening(node))
+        && next
+            .find(|node| node.kind() != SyntaxKind::Space)
+            .is_some_and(|node| node.kind() == SyntaxKind::Hash || is_operand(node))
+}
+
+/// Whether the space at `i` separates primes from a simple base, as in `a ''`.
+fn is_space_before_primes(children: &[&SyntaxNode], i: usize) -> bool {
+    let (Some(prev), Some(next)) = (i.checked_sub(1).map(|j| children[j]), children.get(i + 1))
+    else {
+        return false;
+    };
+    let is_primes = next.kind() == SyntaxKind::MathPrimes
+        || next.kind() == SyntaxKind::MathAttach
+            && next
+                .children()
+                .next()
+                .is_some_and(|base| base.kind() == SyntaxKind::MathPrimes);
+    is_primes
+        && !children[i].leaf_text().has_linebreak()
+        && matches!(
+            prev.kind(),
+            SyntaxKind::MathText | SyntaxKind::MathIdent | SyntaxKind::MathDelimited
+        )
+        && operator_level(prev).is_none()
+}
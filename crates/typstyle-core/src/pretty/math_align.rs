@@ -3,7 +3,7 @@ use typst_syntax::{SyntaxKind, SyntaxNode, ast::*};
 use unicode_width::UnicodeWidthStr;
 
 use super::{Context, PrettyPrinter, context::AlignMode, prelude::*};
-use crate::{AttrStore, ext::StrExt};
+use crate::{AttrStore, WarningKind, ext::StrExt};
 
 impl<'a> PrettyPrinter<'a> {
     /// Attempt to format a math node as an aligned grid if there are align points.
@@ -14,13 +14,28 @@ impl<'a> PrettyPrinter<'a> {
     ) -> Option<ArenaDoc<'a>> {
         // Skip if alignment is disabled or no math align points present
         if ctx.align_mode == AlignMode::Never
-            || !self.attr_store.can_align_in_math(math.to_untyped())
+            || !self.attr_store.has_math_align_point(math.to_untyped())
         {
             return None;
         }
+        if !self.attr_store.can_align_in_math(math.to_untyped()) {
+            self.warn(
+                math.to_untyped(),
+                WarningKind::MathAlignment,
+                "the equation contains a multiline string or raw",
+            );
+            return None;
+        }
         let ctx = ctx.aligned(AlignMode::Outer);
         let raw_aligned = collect_aligned(math, &self.attr_store);
-        let aligned = self.render_aligned(ctx, raw_aligned.rows)?;
+        let Some(aligned) = self.render_aligned(ctx, raw_aligned.rows) else {
+            self.warn(
+                math.to_untyped(),
+                WarningKind::MathAlignment,
+                "the aligned columns do not fit within the maximum width",
+            );
+            return None;
+        };
 
         let doc = self.print_aligned(aligned, raw_aligned.has_trailing_backslash);
         Some(doc)
@@ -371,3 +386,22 @@ fn collect_aligned<'a>(math: Math<'a>, attrs: &AttrStore) -> RawAligned<'a> {
         has_trailing_backslash,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{Config, Typstyle, WarningKind};
+
+    #[test]
+    fn warn_when_math_alignment_is_skipped() {
+        let typstyle = Typstyle::new(Config::new());
+        let formatter = typstyle.format_text("$ a &= \"b\nc\" \\\n  d &= e $\n");
+        formatter.render().unwrap();
+        let warnings = formatter.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind, WarningKind::MathAlignment);
+        assert_eq!(
+            warnings[0].reason,
+            "the equation contains a multiline string or raw"
+        );
+    }
+}
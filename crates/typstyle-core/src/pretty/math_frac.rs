@@ -0,0 +1,194 @@
+use typst_syntax::{SyntaxKind, SyntaxNode, ast::*};
+
+use super::{Context, PrettyPrinter, math::operator_level, math_matrix::has_kind, prelude::*};
+use crate::FracStyle;
+
+impl<'a> PrettyPrinter<'a> {
+    /// Converts the fraction at `i` in the children of math to the style configured for the
+    /// equation, or returns `None` if it is kept as written.
+    pub(super) fn try_convert_frac_style(
+        &'a self,
+        ctx: Context,
+        children: &[&'a SyntaxNode],
+        i: usize,
+    ) -> Option<ArenaDoc<'a>> {
+        let style = if ctx.in_block_equation {
+            self.config.block_frac_style
+        } else {
+            self.config.inline_frac_style
+        };
+        let node = children[i];
+        let prev = i.checked_sub(1).map(|j| children[j]);
+        let next = children.get(i + 1).copied();
+        if style == FracStyle::Preserve
+            || self.attr_store.is_format_disabled(node)
+            || has_kind(
+                node,
+                &[
+                    SyntaxKind::LineComment,
+                    SyntaxKind::BlockComment,
+                    SyntaxKind::Linebreak,
+                    SyntaxKind::MathAlignPoint,
+                ],
+            )
+        {
+            return None;
+        }
+        match style {
+            FracStyle::Preserve => None,
+            FracStyle::Call => {
+                let doc = self.convert_frac_to_call(ctx, node.cast()?)?;
+                // Keep `frac` from merging with a preceding letter, and the args from being
+                // followed by more parentheses.
+                let pad_before = prev
+                    .and_then(|prev| prev.clone().into_text().chars().next_back())
+                    .is_some_and(char::is_alphanumeric);
+                let pad_after = next
+                    .and_then(|next| next.clone().into_text().chars().next())
+                    .is_some_and(|c| c == '(');
+                let space = |pad: bool| {
+                    if pad {
+                        self.arena.space()
+                    } else {
+                        self.arena.nil()
+                    }
+                };
+                Some(space(pad_before) + doc + space(pad_after))
+            }
+            FracStyle::Slash => {
+                // The operands of a slash extend to adjacent letters and attachments.
+                if !prev.is_none_or(is_separator) || !next.is_none_or(is_separator) {
+                    return None;
+                }
+                self.convert_call_to_slash(ctx, node.cast()?)
+            }
+        }
+    }
+
+    /// Converts `a/b` into `frac(a, b)`, dropping the parentheses that Typst drops.
+    fn convert_frac_to_call(&'a self, ctx: Context, frac: MathFrac<'a>) -> Option<ArenaDoc<'a>> {
+        if !self.env.is_builtin("frac") {
+            return None;
+        }
+        let num = self.convert_call_operand(ctx, frac.num())?;
+        let denom = self.convert_call_operand(ctx, frac.denom())?;
+        Some(self.arena.text("frac(") + num + self.arena.text(", ") + denom + self.arena.text(")"))
+    }
+
+    fn convert_call_operand(&'a self, ctx: Context, operand: Expr<'a>) -> Option<ArenaDoc<'a>> {
+        match operand {
+            Expr::MathDelimited(delimited) if is_parenthesized(delimited) => {
+                let body = delimited.body();
+                let mut children = body.to_untyped().children().peekable();
+                // The body must stay a single positional arg.
+                if children.peek().is_none()
+                    || children.any(|child| matches!(child.text().as_str(), "," | ";" | ":"))
+                {
+                    return None;
+                }
+                Some(self.convert_math(ctx, body))
+            }
+            Expr::MathFrac(frac) => self.convert_frac_to_call(ctx, frac),
+            _ => Some(self.convert_expr(ctx, operand)),
+        }
+    }
+
+    /// Converts `frac(a, b)` into `a/b`, adding the parentheses that the operands need.
+    fn convert_call_to_slash(&'a self, ctx: Context, call: MathCall<'a>) -> Option<ArenaDoc<'a>> {
+        let is_frac = match call.callee().to_untyped().clone().into_text().as_str() {
+            "frac" => self.env.is_builtin("frac"),
+            "math.frac" => self.env.is_builtin("math"),
+            _ => false,
+        };
+        if !is_frac
+            || has_kind(
+                call.args().to_untyped(),
+                &[SyntaxKind::LineComment, SyntaxKind::BlockComment],
+            )
+        {
+            return None;
+        }
+        let mut items = call.args().items();
+        let (Some(Arg::Pos(num)), Some(Arg::Pos(denom)), None) =
+            (items.next(), items.next(), items.next())
+        else {
+            return None;
+        };
+        let num = self.convert_slash_operand(ctx, num, false)?;
+        let denom = self.convert_slash_operand(ctx, denom, true)?;
+        Some(num + self.arena.text("/") + denom)
+    }
+
+    fn convert_slash_operand(
+        &'a self,
+        ctx: Context,
+        operand: Expr<'a>,
+        is_denom: bool,
+    ) -> Option<ArenaDoc<'a>> {
+        let nodes = match operand {
+            Expr::Math(math) => {
+                let children = math.to_untyped().children().as_slice();
+                let start = children
+                    .iter()
+                    .position(|child| child.kind() != SyntaxKind::Space)?;
+                let end = children
+                    .iter()
+                    .rposition(|child| child.kind() != SyntaxKind::Space)?;
+                &children[start..=end]
+            }
+            Expr::Array(_) => return None,
+            _ => std::slice::from_ref(operand.to_untyped()),
+        };
+        let [node] = nodes else {
+            let inner = self.convert_math_children(ctx, nodes.iter());
+            return Some(inner.parens());
+        };
+        let expr = node.cast::<Expr>()?;
+        if let Expr::MathCall(call) = expr
+            && let Some(frac) = self.convert_call_to_slash(ctx, call)
+        {
+            // Fractions are left-associative.
+            return Some(if is_denom { frac.parens() } else { frac });
+        }
+        let doc = self.convert_expr(ctx, expr);
+        Some(if is_tight(expr, is_denom) {
+            doc
+        } else {
+            doc.parens()
+        })
+    }
+}
+
+/// Whether the operand binds tighter than a slash, so it needs no parentheses.
+/// Parenthesized operands are wrapped again, since Typst drops one pair of parentheses.
+fn is_tight(expr: Expr, is_denom: bool) -> bool {
+    match expr {
+        Expr::MathText(text) => text
+            .to_untyped()
+            .text()
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '.'),
+        Expr::MathIdent(_)
+        | Expr::MathFieldAccess(_)
+        | Expr::MathCall(_)
+        | Expr::MathAttach(_)
+        | Expr::MathPrimes(_)
+        | Expr::Str(_) => true,
+        Expr::MathDelimited(delimited) => !is_parenthesized(delimited),
+        Expr::MathFrac(_) => !is_denom,
+        _ => false,
+    }
+}
+
+fn is_parenthesized(delimited: MathDelimited) -> bool {
+    delimited.open().to_untyped().text().as_str() == "("
+        && delimited.close().to_untyped().text().as_str() == ")"
+}
+
+/// Whether the node keeps a slash from taking more than the fraction as operands.
+fn is_separator(node: &SyntaxNode) -> bool {
+    match node.kind() {
+        SyntaxKind::Space | SyntaxKind::Linebreak | SyntaxKind::MathAlignPoint => true,
+        _ => operator_level(node).is_some() || matches!(node.text().as_str(), "," | ";"),
+    }
+}
@@ -0,0 +1,226 @@
+use typst_syntax::{SyntaxKind, SyntaxNode, ast::*};
+use unicode_width::UnicodeWidthStr;
+
+use super::{
+    Context, PrettyPrinter,
+    context::{AlignMode, MarkupIndent},
+    prelude::*,
+};
+use crate::ext::StrExt;
+
+impl<'a> PrettyPrinter<'a> {
+    /// Attempt to format the args of `mat`, `vec` or `cases` with one row or entry per line.
+    ///
+    /// Only applies when the args already span multiple lines. Cells of a matrix are padded to
+    /// the widths of their columns, while entries of `vec` and `cases` are only split.
+    pub(super) fn try_convert_math_matrix(
+        &'a self,
+        ctx: Context,
+        math_call: MathCall<'a>,
+    ) -> Option<ArenaDoc<'a>> {
+        let args = math_call.args().to_untyped();
+        let is_matrix = match math_call.callee().to_untyped().clone().into_text().as_str() {
+            "mat" | "math.mat" => true,
+            "vec" | "math.vec" | "cases" | "math.cases" => false,
+            _ => return None,
+        };
+        if !self.attr_store.is_multiline(args) {
+            return None;
+        }
+        let lines = collect_lines(args, is_matrix)?;
+        if !is_matrix && !has_shared_line(args) {
+            return None; // Already one entry per line.
+        }
+
+        // The rows are indented in the matrix, which is itself indented in a broken block equation.
+        let markup_indent = ctx.markup_indent.and_then(MarkupIndent::column);
+        let equation_indent = if ctx.in_block_equation {
+            self.config.tab_spaces
+        } else {
+            0
+        };
+        let indent = markup_indent.unwrap_or_default() + equation_indent + self.config.tab_spaces;
+        let width = self.config.max_width.saturating_sub(indent);
+
+        let ctx = ctx.aligned(AlignMode::Never);
+        let lines = lines
+            .into_iter()
+            .map(|line| {
+                let item = match line.item {
+                    Item::Row(cells) => RenderedItem::Row(self.render_cells(ctx, cells, width)?),
+                    Item::Arg(arg) => RenderedItem::Arg(self.convert_arg(ctx, arg)),
+                };
+                Some((item, line.sep))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let col_widths = matrix_column_widths(&lines, width);
+
+        let body = self.arena.intersperse(
+            lines.into_iter().map(|(item, sep)| {
+                let doc = match item {
+                    RenderedItem::Row(cells) => self.print_row(cells, &col_widths),
+                    RenderedItem::Arg(doc) => doc,
+                };
+                doc + sep.map_or(self.arena.nil(), |sep| self.arena.text(sep))
+            }),
+            self.arena.hardline(),
+        );
+        Some(self.block_indent(body).group().parens())
+    }
+
+    /// Render the cells of a matrix row within the width, each of which must fit on a single line.
+    fn render_cells(
+        &'a self,
+        ctx: Context,
+        cells: Vec<Expr<'a>>,
+        width: usize,
+    ) -> Option<Vec<String>> {
+        cells
+            .into_iter()
+            .map(|cell| {
+                let mut buf = String::new();
+                self.convert_expr(ctx, cell)
+                    .render_fmt(width, &mut buf)
+                    .ok()?;
+                (!buf.has_linebreak()).then_some(buf)
+            })
+            .collect()
+    }
+
+    /// Join the cells of a row with commas, padding all but the last cell.
+    fn print_row(&'a self, cells: Vec<String>, col_widths: &[usize]) -> ArenaDoc<'a> {
+        let num_cells = cells.len();
+        let mut doc = self.arena.nil();
+        for (j, cell) in cells.into_iter().enumerate() {
+            let padding = col_widths
+                .get(j)
+                .map_or(0, |col_width| col_width - cell.width());
+            doc += self.arena.text(cell);
+            if j + 1 < num_cells {
+                doc += self.arena.text(",") + self.arena.spaces(padding + 1);
+            }
+        }
+        doc
+    }
+}
+
+/// Compute the width of each column, or nothing if the padded rows would be wider than `width`.
+fn matrix_column_widths(lines: &[(RenderedItem, Option<&str>)], width: usize) -> Vec<usize> {
+    let rows = lines.iter().filter_map(|(item, _)| match item {
+        RenderedItem::Row(cells) => Some(cells),
+        RenderedItem::Arg(_) => None,
+    });
+    let mut col_widths: Vec<usize> = vec![];
+    for cells in rows {
+        for (j, cell) in cells.iter().enumerate() {
+            match col_widths.get_mut(j) {
+                Some(col_width) => *col_width = (*col_width).max(cell.width()),
+                None => col_widths.push(cell.width()),
+            }
+        }
+    }
+    // Each row is followed by a separator, and cells are separated by `, `.
+    let grid_width = col_widths.iter().sum::<usize>() + 2 * col_widths.len().saturating_sub(1);
+    if grid_width + 1 > width {
+        return vec![];
+    }
+    col_widths
+}
+
+/// A line in the formatted args, with the separator that follows it in the source.
+struct Line<'a> {
+    item: Item<'a>,
+    sep: Option<&'a str>,
+}
+
+enum Item<'a> {
+    /// A matrix row, written as comma-separated cells.
+    Row(Vec<Expr<'a>>),
+    /// A named arg, or an entry of `vec` and `cases`.
+    Arg(Arg<'a>),
+}
+
+enum RenderedItem<'a> {
+    Row(Vec<String>),
+    Arg(ArenaDoc<'a>),
+}
+
+/// Split the args into lines. Returns `None` if the args can not be laid out safely,
+/// e.g., they contain comments, hashes, empty cells, or align points and linebreaks in cells.
+fn collect_lines(args: &SyntaxNode, is_matrix: bool) -> Option<Vec<Line<'_>>> {
+    let mut lines: Vec<Line> = vec![];
+    for child in args.children() {
+        match child.kind() {
+            SyntaxKind::LeftParen | SyntaxKind::RightParen | SyntaxKind::Space => {}
+            SyntaxKind::Comma | SyntaxKind::Semicolon => {
+                let last = lines.last_mut().filter(|line| line.sep.is_none())?;
+                last.sep = Some(child.text().as_str());
+            }
+            SyntaxKind::Array if is_matrix => {
+                let cells = collect_cells(child)?;
+                push_line(&mut lines, Item::Row(cells))?;
+            }
+            SyntaxKind::Named => {
+                push_line(&mut lines, Item::Arg(child.cast()?))?;
+            }
+            SyntaxKind::Array => return None,
+            _ if !is_matrix && !has_kind(child, &[SyntaxKind::Linebreak]) => {
+                push_line(&mut lines, Item::Arg(Arg::Pos(child.cast()?)))?;
+            }
+            _ => return None,
+        }
+    }
+    // A matrix without rows is left to the normal layout.
+    (!is_matrix || lines.iter().any(|line| matches!(line.item, Item::Row(_)))).then_some(lines)
+}
+
+/// Add a line, which must be separated from the previous one.
+fn push_line<'a>(lines: &mut Vec<Line<'a>>, item: Item<'a>) -> Option<()> {
+    if lines.last().is_some_and(|line| line.sep.is_none()) {
+        return None;
+    }
+    lines.push(Line { item, sep: None });
+    Some(())
+}
+
+/// Collect the cells of a matrix row. Each cell must hold exactly one expression.
+fn collect_cells(array: &SyntaxNode) -> Option<Vec<Expr<'_>>> {
+    array
+        .children()
+        .as_slice()
+        .split(|node| node.kind() == SyntaxKind::Comma)
+        .map(|segment| {
+            let mut nodes = segment
+                .iter()
+                .filter(|node| node.kind() != SyntaxKind::Space);
+            let cell = nodes.next()?;
+            if nodes.next().is_some()
+                || has_kind(cell, &[SyntaxKind::Linebreak, SyntaxKind::MathAlignPoint])
+            {
+                return None;
+            }
+            cell.cast()
+        })
+        .collect()
+}
+
+/// Whether the node or any of its descendants is of the given kinds.
+pub(super) fn has_kind(node: &SyntaxNode, kinds: &[SyntaxKind]) -> bool {
+    kinds.contains(&node.kind()) || node.children().any(|child| has_kind(child, kinds))
+}
+
+/// Whether any line in the source holds more than one arg.
+fn has_shared_line(args: &SyntaxNode) -> bool {
+    let mut args_on_line = 0;
+    for child in args.children() {
+        if child.kind() == SyntaxKind::Space && child.text().has_linebreak() {
+            args_on_line = 0;
+        } else if child.is::<Arg>() {
+            args_on_line += 1;
+            if args_on_line > 1 {
+                return true;
+            }
+        }
+    }
+    false
+}
@@ -3,6 +3,7 @@ pub mod prelude;
 pub mod style;
 
 mod args;
+mod code_align;
 mod code_chain;
 mod code_flow;
 mod code_list;
@@ -14,22 +15,30 @@ mod layout;
 mod markup;
 mod math;
 mod math_align;
+mod math_frac;
+mod math_matrix;
 mod parened_expr;
+mod symbol;
 mod table;
 mod text;
 mod util;
 
+use std::cell::RefCell;
+
 pub use context::{Context, Mode};
 use prelude::*;
-use style::{FoldStyle, is_multiline_flavored};
-use typst_syntax::{SyntaxNode, ast::*};
+use style::{FoldStyle, has_magic_trailing_comma, is_multiline_flavored};
+use typst_syntax::{SyntaxKind, SyntaxNode, ast::*};
 
-use crate::{AttrStore, Config, Error, ext::StrExt};
+use crate::{AttrStore, Config, Error, Warning, WarningKind, ext::StrExt, liteval::Env};
 
 pub struct PrettyPrinter<'a> {
     config: Config,
     attr_store: AttrStore,
     arena: Arena<'a>,
+    warnings: RefCell<Vec<Warning>>,
+    /// File-local constants used to evaluate table columns.
+    env: Env,
 }
 
 impl<'a> PrettyPrinter<'a> {
@@ -38,19 +47,59 @@ impl<'a> PrettyPrinter<'a> {
             config,
             attr_store,
             arena: Arena::new(),
+            warnings: Default::default(),
+            env: Env::default(),
         }
     }
 
+    /// Sets the file-local constants used to evaluate table columns.
+    pub fn with_liteval_env(mut self, env: Env) -> Self {
+        self.env = env;
+        self
+    }
+
     pub fn config(&self) -> &Config {
         &self.config
     }
 
+    /// Returns the requested rewrites that were skipped so far.
+    pub fn warnings(&self) -> Vec<Warning> {
+        self.warnings.borrow().clone()
+    }
+
+    /// Records that a requested rewrite of the node was skipped.
+    fn warn(&self, node: &SyntaxNode, kind: WarningKind, reason: impl Into<String>) {
+        let warning = Warning {
+            span: node.span(),
+            kind,
+            reason: reason.into(),
+        };
+        let mut warnings = self.warnings.borrow_mut();
+        // A node may be converted more than once when trying different layouts.
+        if !warnings.contains(&warning) {
+            warnings.push(warning);
+        }
+    }
+
     fn get_fold_style(&self, ctx: Context, node: impl AstNode<'a>) -> FoldStyle {
         self.get_fold_style_untyped(ctx, node.to_untyped())
     }
 
     fn get_fold_style_untyped(&self, ctx: Context, node: &'a SyntaxNode) -> FoldStyle {
-        let is_multiline = is_multiline_flavored(node);
+        let is_multiline = if self.config.magic_trailing_comma
+            && matches!(
+                node.kind(),
+                SyntaxKind::Args
+                    | SyntaxKind::Array
+                    | SyntaxKind::Dict
+                    | SyntaxKind::Params
+                    | SyntaxKind::Destructuring
+            ) {
+            // The trailing comma replaces flavor detection, so removing it collapses the list.
+            has_magic_trailing_comma(node)
+        } else {
+            is_multiline_flavored(node)
+        };
         if ctx.break_suppressed {
             return if is_multiline {
                 FoldStyle::Fit
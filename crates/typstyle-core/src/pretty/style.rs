@@ -28,3 +28,28 @@ pub fn is_multiline_flavored(node: &SyntaxNode) -> bool {
     }
     false
 }
+
+/// A syntax node has a magic trailing comma, if a comma follows its last item before the closing
+/// parenthesis. The comma required after the only item of an array or destructuring is not magic.
+pub fn has_magic_trailing_comma(node: &SyntaxNode) -> bool {
+    let children = node.children().as_slice();
+    // Args may be followed by trailing content blocks.
+    let Some(rparen) = children
+        .iter()
+        .rposition(|child| child.kind() == SyntaxKind::RightParen)
+    else {
+        return false;
+    };
+    let mut before = children[..rparen]
+        .iter()
+        .rev()
+        .filter(|child| !child.kind().is_trivia());
+    if before
+        .next()
+        .is_none_or(|child| child.kind() != SyntaxKind::Comma)
+    {
+        return false;
+    }
+    !matches!(node.kind(), SyntaxKind::Array | SyntaxKind::Destructuring)
+        || before.any(|child| child.kind() == SyntaxKind::Comma)
+}
@@ -0,0 +1,166 @@
+use std::ptr;
+
+use typst_syntax::{SyntaxKind, SyntaxNode, ast::*};
+
+use super::{PrettyPrinter, prelude::*};
+use crate::SymbolStyle;
+
+/// Math shorthands and the names of the symbols they stand for.
+///
+/// This is a deliberate subset of Typst's shorthands. Single-character shorthands such as `-`, `*`
+/// and `~` are left out, as they are written far more often than their names. So are `[|`, `|]`
+/// and `||`, which Typst matches as delimiters, while their names are not.
+///
+/// The consistency tests of `math/symbol-table-*.typ` check every pair against Typst, and must be
+/// updated with this table.
+const MATH_SYMBOLS: &[(&str, &str)] = &[
+    ("->", "arrow.r"),
+    ("|->", "arrow.r.bar"),
+    ("=>", "arrow.r.double"),
+    ("|=>", "arrow.r.double.bar"),
+    ("==>", "arrow.r.double.long"),
+    ("-->", "arrow.r.long"),
+    ("~~>", "arrow.r.long.squiggly"),
+    ("~>", "arrow.r.squiggly"),
+    (">->", "arrow.r.tail"),
+    ("->>", "arrow.r.twohead"),
+    ("<-", "arrow.l"),
+    ("<==", "arrow.l.double.long"),
+    ("<--", "arrow.l.long"),
+    ("<~~", "arrow.l.long.squiggly"),
+    ("<~", "arrow.l.squiggly"),
+    ("<-<", "arrow.l.tail"),
+    ("<<-", "arrow.l.twohead"),
+    ("<->", "arrow.l.r"),
+    ("<=>", "arrow.l.r.double"),
+    ("<==>", "arrow.l.r.double.long"),
+    ("<-->", "arrow.l.r.long"),
+    (":=", "colon.eq"),
+    ("::=", "colon.double.eq"),
+    ("...", "dots.h"),
+    ("=:", "eq.colon"),
+    ("!=", "eq.not"),
+    (">>", "gt.double"),
+    (">=", "gt.eq"),
+    (">>>", "gt.triple"),
+    ("<<", "lt.double"),
+    ("<=", "lt.eq"),
+    ("<<<", "lt.triple"),
+];
+
+/// Markup shorthands and the names of the symbols they stand for.
+///
+/// The `-` before digits is left out, as it would turn numbers into code.
+const MARKUP_SYMBOLS: &[(&str, &str)] = &[
+    ("--", "dash.en"),
+    ("---", "dash.em"),
+    ("~", "space.nobreak"),
+    ("-?", "hyph.soft"),
+    ("...", "dots.h"),
+];
+
+/// A symbol in math written in the configured style.
+pub(super) struct Respelling {
+    pub text: &'static str,
+    /// Whether a space is needed to keep the symbol from merging with the previous node.
+    pub pad_before: bool,
+    /// Whether a space is needed to keep the symbol from merging with the next node.
+    pub pad_after: bool,
+}
+
+impl<'a> PrettyPrinter<'a> {
+    /// Returns the symbol at `i` in the children of math spelled in the configured style,
+    /// or `None` if it is already spelled so or has no spelling in that style.
+    pub(super) fn respell_math_symbol(
+        &self,
+        children: &[&SyntaxNode],
+        i: usize,
+    ) -> Option<Respelling> {
+        let node = children[i];
+        if self.attr_store.is_format_disabled(node) {
+            return None;
+        }
+        let (text, merges): (_, fn(char) -> bool) = match self.config.symbol_style {
+            SymbolStyle::Preserve => return None,
+            SymbolStyle::Shorthand => {
+                if !matches!(
+                    node.kind(),
+                    SyntaxKind::MathIdent | SyntaxKind::MathFieldAccess
+                ) {
+                    return None;
+                }
+                let path = node.clone().into_text();
+                let name = path.strip_prefix("sym.").unwrap_or(path.as_str());
+                let root = path.split('.').next()?;
+                if !self.env.is_builtin(root) {
+                    return None; // Shadowed by a user binding.
+                }
+                let &(shorthand, _) = MATH_SYMBOLS.iter().find(|(_, it)| *it == name)?;
+                (shorthand, |c| "!*+-./:<=>[]|~".contains(c))
+            }
+            SymbolStyle::Named => {
+                if node.kind() != SyntaxKind::MathShorthand {
+                    return None;
+                }
+                let text = node.text().as_str();
+                let &(_, name) = MATH_SYMBOLS.iter().find(|(it, _)| *it == text)?;
+                let root = name.split('.').next()?;
+                if !self.env.is_builtin(root) {
+                    return None;
+                }
+                (name, |c| c.is_alphanumeric() || "_.(".contains(c))
+            }
+        };
+        // Neighboring shorthands may be respelled as names too.
+        let respelled = |node: &SyntaxNode| {
+            self.config.symbol_style == SymbolStyle::Named
+                && node.kind() == SyntaxKind::MathShorthand
+        };
+        let pad_before = i.checked_sub(1).is_some_and(|j| {
+            respelled(children[j])
+                || (children[j].clone().into_text().chars().next_back()).is_some_and(merges)
+        });
+        let pad_after = children.get(i + 1).is_some_and(|next| {
+            respelled(next) || (next.clone().into_text().chars().next()).is_some_and(merges)
+        });
+        Some(Respelling {
+            text,
+            pad_before,
+            pad_after,
+        })
+    }
+
+    /// Converts a markup shorthand, writing it as a `sym` name if configured.
+    /// The `next` node decides whether the name must be closed with a semicolon.
+    pub(super) fn convert_markup_shorthand(
+        &'a self,
+        shorthand: Shorthand<'a>,
+        next: Option<&SyntaxNode>,
+    ) -> ArenaDoc<'a> {
+        let text = shorthand.to_untyped().text().as_str();
+        let name = MARKUP_SYMBOLS.iter().find(|(it, _)| *it == text);
+        match name {
+            Some((_, name))
+                if self.config.symbol_style == SymbolStyle::Named
+                    && self.env.is_builtin("sym")
+                    && !self.attr_store.is_format_disabled(shorthand.to_untyped()) =>
+            {
+                let continues = next
+                    .and_then(|next| next.clone().into_text().chars().next())
+                    .is_some_and(|c| c.is_alphanumeric() || "_-.([".contains(c));
+                self.arena
+                    .text(format!("#sym.{name}{}", if continues { ";" } else { "" }))
+            }
+            _ => self.convert_trivia(shorthand),
+        }
+    }
+}
+
+/// Returns the node that follows `node` in `nodes`.
+pub(super) fn next_sibling<'a>(
+    nodes: &[&'a SyntaxNode],
+    node: &SyntaxNode,
+) -> Option<&'a SyntaxNode> {
+    let i = nodes.iter().position(|it| ptr::eq(*it, node))?;
+    nodes.get(i + 1).copied()
+}
@@ -2,57 +2,105 @@ use typst_syntax::{SyntaxKind, SyntaxNode, ast::*};
 
 use super::{Context, prelude::*, util::func_name};
 use crate::{
-    PrettyPrinter,
+    PrettyPrinter, TrailingComma, WarningKind,
     ext::StrExt,
+    liteval::Env,
     pretty::{Mode, layout::table::TableCollector},
 };
 
 impl<'a> PrettyPrinter<'a> {
+    /// Returns the name of the columns argument if the call is formatted as a table.
+    pub(super) fn table_columns_arg(&'a self, func_call: FuncCall<'a>) -> Option<&'a str> {
+        if is_table(func_call) {
+            return Some("columns");
+        }
+        let path = callee_path(func_call.callee())?;
+        self.config.table_functions.iter().find_map(|entry| {
+            let (entry_path, columns_arg) =
+                entry.split_once(':').unwrap_or((entry.as_str(), "columns"));
+            (entry_path.trim() == path).then(|| columns_arg.trim())
+        })
+    }
+
     pub(super) fn try_convert_table(
         &'a self,
         ctx: Context,
         table: FuncCall<'a>,
+        columns_arg: &str,
         paren_nodes: &'a [SyntaxNode],
     ) -> Option<ArenaDoc<'a>> {
         // NOTE: args are not empty here
-        let columns = if is_table(table) && is_table_formattable(table, paren_nodes) {
-            get_table_columns(table)
-        } else {
-            None
-        }?;
-        Some(self.convert_table(ctx, paren_nodes, columns))
+        if !has_pos_args(paren_nodes) {
+            return None;
+        }
+        if has_block_comments(table) {
+            self.warn(
+                table.to_untyped(),
+                WarningKind::TableReflow,
+                "the arguments contain block comments",
+            );
+            return None;
+        }
+        let Some(columns) = get_table_columns(table, columns_arg, &self.env) else {
+            self.warn(
+                table.to_untyped(),
+                WarningKind::TableReflow,
+                "the number of columns cannot be determined",
+            );
+            return None;
+        };
+        Some(self.convert_table(ctx, paren_nodes, columns, !is_table(table)))
     }
 
     /// Handle parenthesized args of a table.
+    ///
+    /// `custom` is set for the functions from `table_functions`, whose special cells may be named
+    /// like those of tablex.
     pub(super) fn convert_table(
         &'a self,
         ctx: Context,
         paren_nodes: &'a [SyntaxNode],
         columns: usize,
+        custom: bool,
     ) -> ArenaDoc<'a> {
         let ctx = ctx.with_mode(Mode::CodeCont);
 
         // Rules:
         // - named/spread args, header/footer: occupy a line.
-        // - reflow cells if no special cells (cell, hline, vline, )
+        // - reflow cells if no special cells (cell, hline, vline, spread)
         // - hard break at linebreaks with at least 1 empty lines
-        let can_reflow_cells = paren_nodes
+        let special_cell = paren_nodes
             .iter()
-            .any(|it| it.cast().is_some_and(is_special_cell));
-        let mut collector =
-            TableCollector::new(&self.arena, if can_reflow_cells { 0 } else { columns });
+            .find_map(|it| Some((it, special_cell_reason(it.cast()?, custom)?)));
+        if let Some((cell, reason)) = &special_cell {
+            self.warn(cell, WarningKind::TableReflow, reason.as_str());
+        }
+        let trailing_sep = match self.config.trailing_comma {
+            TrailingComma::AlwaysWhenMultiline => true,
+            TrailingComma::Never => false,
+            TrailingComma::Preserve => paren_nodes
+                .iter()
+                .rfind(|it| !it.kind().is_trivia())
+                .is_some_and(|it| it.kind() == SyntaxKind::Comma),
+        };
+        let mut collector = TableCollector::new(
+            &self.arena,
+            if special_cell.is_some() { 0 } else { columns },
+            trailing_sep,
+        )
+        .with_align(self.config.table_align, self.config.max_width);
 
         for node in paren_nodes.iter() {
             if let Some(arg) = node.cast::<Arg>() {
                 match arg {
-                    Arg::Pos(Expr::FuncCall(func_call)) if is_header_footer(func_call) => {
+                    Arg::Pos(Expr::FuncCall(func_call)) if is_header_footer(func_call, custom) => {
                         // This func_call does not pass the escape-hatch check in `convert_expr`.
                         let doc = if let Some(res) = self.check_disabled(func_call.to_untyped()) {
                             res
                         } else {
                             self.convert_expr(ctx, func_call.callee())
                                 + self.convert_args(ctx, func_call.args(), |nodes| {
-                                    self.convert_table(ctx, nodes, columns)
+                                    self.convert_table(ctx, nodes, columns, custom)
                                 })
                         };
                         collector.push_row(doc);
@@ -79,32 +127,44 @@ impl<'a> PrettyPrinter<'a> {
     }
 }
 
-pub fn is_table(func_call: FuncCall) -> bool {
+fn is_table(func_call: FuncCall) -> bool {
     matches!(func_name(func_call), Some("table") | Some("grid"))
 }
 
-fn is_table_formattable(func_call: FuncCall, paren_nodes: &[SyntaxNode]) -> bool {
-    // 1. no block comments
-    if func_call
+/// Returns the dotted path of a callee made of identifiers and field accesses.
+fn callee_path(callee: Expr) -> Option<String> {
+    match callee {
+        Expr::Ident(ident) => Some(ident.as_str().to_string()),
+        Expr::FieldAccess(access) => {
+            Some(callee_path(access.target())? + "." + access.field().as_str())
+        }
+        _ => None,
+    }
+}
+
+fn has_block_comments(func_call: FuncCall) -> bool {
+    func_call
         .args()
         .to_untyped()
         .children()
         .any(|it| matches!(it.kind(), SyntaxKind::BlockComment))
-    {
-        return false;
-    }
-    // 2. has at least one pos arg
+}
+
+fn has_pos_args(paren_nodes: &[SyntaxNode]) -> bool {
     paren_nodes
         .iter()
         .any(|it| matches!(it.cast::<Arg>(), Some(Arg::Pos(_))))
 }
 
-fn get_table_columns(func_call: FuncCall) -> Option<usize> {
+/// The largest number of columns to reflow cells into, which bounds the memory of a row.
+const MAX_COLUMNS: usize = 1 << 12;
+
+fn get_table_columns(func_call: FuncCall, columns_arg: &str, env: &Env) -> Option<usize> {
     use crate::liteval::{Liteval, Value};
 
     let Some(columns_expr) = func_call.args().items().find_map(|node| {
         if let Arg::Named(named) = node
-            && named.name().as_str() == "columns"
+            && named.name().as_str() == columns_arg
         {
             return Some(named.expr());
         }
@@ -116,28 +176,95 @@ fn get_table_columns(func_call: FuncCall) -> Option<usize> {
             Some(1) // if not `columns` is provided, regard as 1.
         };
     };
-    match columns_expr.liteval() {
-        Ok(Value::Auto) => Some(1),
-        Ok(Value::Int(i)) => Some(i as usize),
-        Ok(Value::Array(a)) => Some(a),
-        _ => None,
-    }
+    let columns = match columns_expr.liteval_in(env) {
+        Ok(Value::Auto) => 1,
+        Ok(Value::Int(i)) => usize::try_from(i).ok()?,
+        Ok(Value::Array(a)) => a,
+        _ => return None,
+    };
+    (columns <= MAX_COLUMNS).then_some(columns)
 }
 
-fn is_header_footer(func_call: FuncCall) -> bool {
+fn is_header_footer(func_call: FuncCall, custom: bool) -> bool {
     const HEADER_FOOTER: &[&str] = &["header", "footer"];
 
-    func_name(func_call).is_some_and(|name| HEADER_FOOTER.contains(&name))
+    func_name(func_call)
+        .is_some_and(|name| HEADER_FOOTER.contains(&strip_custom_suffix(name, custom)))
+}
+
+/// Strips the `x` suffix of tablex from the names in custom table functions.
+fn strip_custom_suffix(name: &str, custom: bool) -> &str {
+    if custom {
+        name.strip_suffix('x').unwrap_or(name)
+    } else {
+        name
+    }
 }
 
-fn is_special_cell(arg: Arg) -> bool {
+/// Returns why the argument may not take up exactly one cell, if so.
+///
+/// In custom table functions, the names may also end in `x`, and `colspan` and `rowspan` are
+/// special too, as in tablex.
+fn special_cell_reason(arg: Arg, custom: bool) -> Option<String> {
     const BLACK_LIST: &[&str] = &["cell", "vline", "hline"];
+    const CUSTOM_BLACK_LIST: &[&str] = &["cell", "vline", "hline", "colspan", "rowspan"];
 
     match arg {
         Arg::Pos(Expr::FuncCall(func_call)) => {
-            func_name(func_call).is_some_and(|name| BLACK_LIST.contains(&name))
+            let name = func_name(func_call)?;
+            let black_list = if custom {
+                CUSTOM_BLACK_LIST
+            } else {
+                BLACK_LIST
+            };
+            let is_special = black_list.contains(&strip_custom_suffix(name, custom));
+            is_special.then(|| format!("the cells contain `{name}`"))
         }
-        Arg::Spread(_) => true,
-        _ => false,
+        Arg::Spread(_) => Some("the cells contain spread arguments".to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Config, Typstyle, WarningKind};
+
+    fn table_warnings(input: &str) -> Vec<String> {
+        let typstyle = Typstyle::new(Config::new());
+        let formatter = typstyle.format_text(input);
+        formatter.render().unwrap();
+        formatter
+            .warnings()
+            .into_iter()
+            .map(|warning| {
+                assert_eq!(warning.kind, WarningKind::TableReflow);
+                warning.reason
+            })
+            .collect()
+    }
+
+    #[test]
+    fn warn_when_table_reflow_is_skipped() {
+        assert!(table_warnings("#table(columns: 2, [a], [b])\n").is_empty());
+        assert_eq!(
+            table_warnings("#table(columns: cols, [a], [b])\n"),
+            ["the number of columns cannot be determined"]
+        );
+        assert_eq!(
+            table_warnings("#table(columns: range(9223372036854775807), [a], [b])\n"),
+            ["the number of columns cannot be determined"]
+        );
+        assert_eq!(
+            table_warnings("#table(columns: 2, /* a */ [a], [b])\n"),
+            ["the arguments contain block comments"]
+        );
+        assert_eq!(
+            table_warnings("#table(columns: 2, table.hline(), [a], [b])\n"),
+            ["the cells contain `hline`"]
+        );
+        assert_eq!(
+            table_warnings("#table(columns: 2, [a], ..cells)\n"),
+            ["the cells contain spread arguments"]
+        );
     }
 }
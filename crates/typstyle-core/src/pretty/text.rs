@@ -1,7 +1,8 @@
+use icu_segmenter::{LineSegmenter, LineSegmenterBorrowed};
 use typst_syntax::{SyntaxNode, ast::*};
 
 use super::{Context, PrettyPrinter, prelude::*};
-use crate::ext::StrExt;
+use crate::{CjkBreak, ext::StrExt};
 
 impl<'a> PrettyPrinter<'a> {
     pub(super) fn convert_text(&'a self, text: Text<'a>) -> ArenaDoc<'a> {
@@ -12,7 +13,7 @@ impl<'a> PrettyPrinter<'a> {
     pub(super) fn convert_text_wrapped(&'a self, text: Text<'a>) -> ArenaDoc<'a> {
         // For sentence-per-line mode, we need to handle it at the markup level,
         // not at individual text nodes. So just use regular wrapping here.
-        wrap_text(&self.arena, text.get())
+        wrap_text(&self.arena, text.get(), self.config.cjk_break)
     }
 
     pub(super) fn convert_space(&'a self, ctx: Context, space: Space<'a>) -> ArenaDoc<'a> {
@@ -43,11 +44,24 @@ impl<'a> PrettyPrinter<'a> {
 /// except before tokens that can be parsed as enum markers, where we use a hard space.
 ///
 /// See: https://github.com/typst/typst/blob/8ace67d942a4b8c6b9d95b73b3a39f5d0259c7b2/crates/typst-syntax/src/lexer.rs#L479-L488
-fn wrap_text<'a>(arena: &'a Arena<'a>, text: &'a str) -> ArenaDoc<'a> {
+fn wrap_text<'a>(arena: &'a Arena<'a>, text: &'a str, cjk_break: CjkBreak) -> ArenaDoc<'a> {
+    let segmenter = (cjk_break == CjkBreak::Allow && text.chars().any(is_cjk))
+        .then(|| LineSegmenter::new_for_non_complex_scripts(Default::default()));
+    let word = |token: &'a str| match &segmenter {
+        None => arena.text(token),
+        // breaks between CJK characters add no space when not taken
+        Some(segmenter) => arena.intersperse(
+            split_cjk_breaks(segmenter, token)
+                .into_iter()
+                .map(|part| arena.text(part)),
+            arena.line_().group(),
+        ),
+    };
+
     let mut tokens = text.split_ascii_whitespace();
     // start with first token (or nil() if empty)
     let mut doc = if let Some(first) = tokens.next() {
-        arena.text(first)
+        word(first)
     } else {
         return arena.nil();
     };
@@ -59,7 +73,7 @@ fn wrap_text<'a>(arena: &'a Arena<'a>, text: &'a str) -> ArenaDoc<'a> {
         } else {
             arena.softline()
         };
-        doc + sep + arena.text(token)
+        doc + sep + word(token)
     });
 
     // preserve a trailing space as a final softline
@@ -82,6 +96,50 @@ pub(super) fn is_enum_marker(token: &str) -> bool {
     false
 }
 
+/// Splits `word` at the line break opportunities between two CJK characters.
+pub(super) fn split_cjk_breaks<'w>(
+    segmenter: &LineSegmenterBorrowed<'static>,
+    word: &'w str,
+) -> Vec<&'w str> {
+    if !word.chars().any(is_cjk) {
+        return vec![word];
+    }
+    let mut parts = vec![];
+    let mut start = 0;
+    for pos in segmenter.segment_str(word) {
+        if pos == 0 || pos == word.len() {
+            continue;
+        }
+        if word[..pos].chars().next_back().is_some_and(is_cjk)
+            && word[pos..].chars().next().is_some_and(is_cjk)
+        {
+            parts.push(&word[start..pos]);
+            start = pos;
+        }
+    }
+    parts.push(&word[start..]);
+    parts
+}
+
+/// Returns `true` for Han characters, kana, and CJK and fullwidth punctuation.
+/// Hangul and fullwidth letters are not included, as they form words like Latin letters.
+pub(super) fn is_cjk(c: char) -> bool {
+    matches!(
+        c,
+        '\u{3000}'..='\u{30FF}' // CJK punctuation, Hiragana and Katakana
+            | '\u{3100}'..='\u{312F}' // Bopomofo
+            | '\u{31F0}'..='\u{31FF}' // Katakana Phonetic Extensions
+            | '\u{3400}'..='\u{4DBF}' // CJK Unified Ideographs Extension A
+            | '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+            | '\u{F900}'..='\u{FAFF}' // CJK Compatibility Ideographs
+            | '\u{FF01}'..='\u{FF0F}'
+            | '\u{FF1A}'..='\u{FF20}'
+            | '\u{FF3B}'..='\u{FF40}'
+            | '\u{FF5B}'..='\u{FF65}' // Fullwidth and halfwidth punctuation
+            | '\u{20000}'..='\u{3FFFF}' // Supplementary Ideographic Planes
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use insta::assert_snapshot;
@@ -93,7 +151,7 @@ mod tests {
         let text = "a. 1. 01. 18446744073709551615. 18446744073709551616. 18446744073709551617.";
 
         let arena = Arena::new();
-        let doc = wrap_text(&arena, text);
+        let doc = wrap_text(&arena, text, CjkBreak::Never);
 
         assert_snapshot!(doc.print(0).to_string(), @r"
         a. 1. 01. 18446744073709551615.
@@ -101,4 +159,14 @@ mod tests {
         18446744073709551617.
         ")
     }
+
+    #[test]
+    fn cjk_breaks_keep_latin_words() {
+        let segmenter = LineSegmenter::new_for_non_complex_scripts(Default::default());
+        assert_eq!(
+            split_cjk_breaks(&segmenter, "中文。Typst是排版"),
+            ["中", "文。Typst是", "排", "版"]
+        );
+        assert_eq!(split_cjk_breaks(&segmenter, "well-known"), ["well-known"]);
+    }
 }
@@ -0,0 +1,114 @@
+//! Alignment of trailing line comments, applied to the rendered text.
+//!
+//! Line comments are printed as line suffixes, one space after the code, so the column they land
+//! in is only known after rendering. We therefore reparse the output to find them.
+//! Comments inside nodes with formatting disabled are left where they are.
+
+use typst_syntax::{SyntaxKind, SyntaxNode};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{AttrStore, pretty::Mode};
+
+/// A line comment that follows code on the same line.
+struct TrailingComment {
+    line: usize,
+    indent: usize,
+    /// Byte offset of the end of the code before the comment.
+    code_end: usize,
+    /// Byte offset of the start of the comment.
+    start: usize,
+    code_width: usize,
+    comment_width: usize,
+}
+
+/// Aligns trailing line comments on consecutive lines with the same indentation in one column.
+///
+/// The column is capped so that comments do not exceed `max_width`. Only existing whitespace
+/// before a comment is widened, so comments that directly follow markup text are untouched.
+/// `first_column` is the column where the text starts in its source.
+pub(crate) fn align_trailing_comments(
+    text: &str,
+    mode: Mode,
+    first_column: usize,
+    max_width: usize,
+) -> String {
+    let root = match mode {
+        Mode::Markup => typst_syntax::parse(text),
+        Mode::Code | Mode::CodeCont => typst_syntax::parse_code(text),
+        Mode::Math => typst_syntax::parse_math(text),
+    };
+    if root.erroneous() {
+        return text.to_string();
+    }
+
+    let attrs = AttrStore::new(&root);
+    let mut comments = vec![];
+    collect_line_comments(&root, 0, &attrs, &mut comments);
+
+    let mut trailing: Vec<TrailingComment> = vec![];
+    let (mut line, mut scanned) = (0, 0);
+    for (start, end) in comments {
+        line += text[scanned..start].matches('\n').count();
+        scanned = start;
+        let line_start = text[..start].rfind('\n').map_or(0, |i| i + 1);
+        let prefix = &text[line_start..start];
+        let code = prefix.trim_end();
+        if code.trim_start().is_empty() || code.len() == prefix.len() {
+            continue;
+        }
+        trailing.push(TrailingComment {
+            line,
+            indent: prefix.len() - prefix.trim_start().len(),
+            code_end: line_start + code.len(),
+            start,
+            code_width: code.width() + if line == 0 { first_column } else { 0 },
+            comment_width: text[start..end].width(),
+        });
+    }
+
+    let mut res = String::with_capacity(text.len());
+    let mut cursor = 0;
+    for run in
+        trailing.chunk_by(|prev, next| next.line == prev.line + 1 && next.indent == prev.indent)
+    {
+        if run.len() < 2 {
+            continue;
+        }
+        let column = run
+            .iter()
+            .map(|it| it.code_width + 1)
+            .max()
+            .unwrap_or_default();
+        for comment in run {
+            let target = column
+                .min(max_width.saturating_sub(comment.comment_width))
+                .max(comment.code_width + 1);
+            res.push_str(&text[cursor..comment.code_end]);
+            res.push_str(&" ".repeat(target - comment.code_width));
+            cursor = comment.start;
+        }
+    }
+    res.push_str(&text[cursor..]);
+    res
+}
+
+/// Collects the byte ranges of all line comments in the node, except those in disabled nodes.
+fn collect_line_comments(
+    node: &SyntaxNode,
+    offset: usize,
+    attrs: &AttrStore,
+    comments: &mut Vec<(usize, usize)>,
+) {
+    if attrs.is_format_disabled(node) {
+        return;
+    }
+    if node.kind() == SyntaxKind::LineComment {
+        comments.push((offset, offset + node.len()));
+        return;
+    }
+    let mut offset = offset;
+    for child in node.children() {
+        collect_line_comments(child, offset, attrs, comments);
+        offset += child.len();
+    }
+}
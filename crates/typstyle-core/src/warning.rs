@@ -0,0 +1,43 @@
+use std::fmt;
+
+use typst_syntax::Span;
+
+/// A rewrite requested by the configuration that the formatter skipped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    /// The span of the node that was left as it is.
+    pub span: Span,
+    /// The kind of the skipped rewrite.
+    pub kind: WarningKind,
+    /// Why the rewrite was skipped.
+    pub reason: String,
+}
+
+/// The kinds of rewrites that can be skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum WarningKind {
+    /// Sorting import items or import statements.
+    ImportSorting,
+    /// Reflowing the cells of a table or grid into columns.
+    TableReflow,
+    /// Aligning the columns of an equation at its align points.
+    MathAlignment,
+}
+
+impl fmt::Display for WarningKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::ImportSorting => "import sorting",
+            Self::TableReflow => "table reflow",
+            Self::MathAlignment => "math alignment",
+        })
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "skipped {}: {}", self.kind, self.reason)
+    }
+}
@@ -102,6 +102,15 @@ fn rust_type_to_ts_type(ast: &syn::File, ty: &Type) -> Option<String> {
             | "f32" | "f64" => Some("number".to_string()),
             "bool" => Some("boolean".to_string()),
             "String" => Some("string".to_string()),
+            "Vec" => {
+                let syn::PathArguments::AngleBracketed(args) = &last_segment.arguments else {
+                    return None;
+                };
+                let Some(syn::GenericArgument::Type(elem)) = args.args.first() else {
+                    return None;
+                };
+                Some(format!("{}[]", rust_type_to_ts_type(ast, elem)?))
+            }
             _ => ast.items.iter().find_map(|item| {
                 let Item::Enum(item_enum) = item else {
                     return None;
@@ -4,7 +4,8 @@ use js_sys::Error;
 use serde::Serialize;
 use typst_syntax::Source;
 use typstyle_core::{
-    Config, SpanMapping, Typstyle, format_ast, format_ast_with_mapping, partial::format_range_ast,
+    Config, SpanMapping, Typstyle, WarningKind, format_ast, format_ast_with_mapping,
+    partial::format_range_ast,
 };
 use wasm_bindgen::prelude::*;
 
@@ -42,6 +43,56 @@ pub fn format(
     t.format_text(text).render().map_err(into_error)
 }
 
+/// A requested rewrite that was skipped, serialized to JS via serde.
+#[derive(Serialize)]
+struct FormatWarning {
+    kind: WarningKind,
+    reason: String,
+    /// Start UTF-16 code unit index of the node that was left as it is
+    start: usize,
+    /// End UTF-16 code unit index of the node that was left as it is
+    end: usize,
+}
+
+/// Result of formatting with warnings, serialized to JS via serde.
+#[derive(Serialize)]
+struct FormatWithWarningsResult {
+    text: String,
+    warnings: Vec<FormatWarning>,
+}
+
+/// Formats the content and reports the requested rewrites that were skipped.
+/// Returns a JS object: { text: string, warnings: { kind, reason, start, end }[] }
+#[wasm_bindgen]
+pub fn format_with_warnings(
+    text: &str,
+    #[wasm_bindgen(unchecked_param_type = "Partial<Config>")] config: JsValue,
+) -> Result<JsValue, Error> {
+    let config = parse_config(config)?;
+    let t = Typstyle::new(config);
+    let source = Source::detached(text);
+    let f = t.format_source(source.clone());
+    let formatted = f.render().map_err(into_error)?;
+    let warnings = f
+        .warnings()
+        .into_iter()
+        .map(|warning| {
+            let range = source.range(warning.span).unwrap_or_default();
+            FormatWarning {
+                kind: warning.kind,
+                reason: warning.reason,
+                start: source.lines().byte_to_utf16(range.start).unwrap_or(0),
+                end: source.lines().byte_to_utf16(range.end).unwrap_or(0),
+            }
+        })
+        .collect();
+    let result = FormatWithWarningsResult {
+        text: formatted,
+        warnings,
+    };
+    serde_wasm_bindgen::to_value(&result).map_err(into_error)
+}
+
 /// Get the pretty IR for the content.
 #[wasm_bindgen]
 pub fn format_ir(
@@ -29,6 +29,10 @@ pub struct CliArguments {
     #[arg(long, default_value_t = false, global = true, conflicts_with = "check")]
     pub diff: bool,
 
+    /// Format only the local files reachable from this entrypoint via `#import` and `#include`
+    #[arg(long, conflicts_with = "input")]
+    pub entry: Option<PathBuf>,
+
     #[command(flatten, next_help_heading = "Format Configuration")]
     pub style: StyleArgs,
 
@@ -41,7 +45,7 @@ pub struct CliArguments {
 
 impl CliArguments {
     pub fn validate_input(&self) {
-        if self.command.is_none() && self.inplace && self.input.is_empty() {
+        if self.command.is_none() && self.inplace && self.input.is_empty() && self.entry.is_none() {
             let mut cmd = Self::command();
             cmd.error(
                 ErrorKind::ValueValidation,
@@ -62,6 +66,36 @@ pub enum Command {
         #[arg(value_enum)]
         shell: clap_complete::Shell,
     },
+    #[cfg(feature = "verify")]
+    /// Verify that a project compiles to the same document after formatting
+    Verify(VerifyArgs),
+}
+
+#[cfg(feature = "verify")]
+#[derive(Args)]
+pub struct VerifyArgs {
+    /// Root directory of the project
+    pub root: PathBuf,
+
+    /// Entrypoint to compile, relative to the project root
+    #[arg(long)]
+    pub entry: PathBuf,
+
+    /// Additional directories to search for fonts
+    #[arg(long = "font-path", value_name = "DIR")]
+    pub font_paths: Vec<PathBuf>,
+
+    /// Ignore fonts installed on the system
+    #[arg(long, default_value_t = false)]
+    pub ignore_system_fonts: bool,
+
+    /// Compare the HTML export instead of the paged document
+    #[arg(long, default_value_t = false)]
+    pub html: bool,
+
+    /// Write an HTML report with renders and source diffs of any differences to this file
+    #[arg(long, value_name = "FILE")]
+    pub report: Option<PathBuf>,
 }
 
 #[derive(Args)]
@@ -91,7 +125,15 @@ pub struct StyleArgs {
     #[arg(long, default_value_t = false, global = true)]
     pub no_reorder_import_items: bool,
 
-    /// Text wrapping mode: none (default), fill (wrap to line width), or sentence (one per line).
+    /// Sort consecutive import statements by their sources.
+    #[arg(long, default_value_t = false, global = true)]
+    pub reorder_imports: bool,
+
+    /// Merge consecutive imports of items from the same source.
+    #[arg(long, default_value_t = false, global = true)]
+    pub merge_imports: bool,
+
+    /// Text wrapping mode: none (default), fill (wrap to line width), sentence (one per line), or clause (one per line, breaking long sentences at clauses).
     #[arg(
         long,
         value_enum,
@@ -102,6 +144,103 @@ pub struct StyleArgs {
         global = true
     )]
     pub wrap_text: WrapTextMode,
+
+    /// How to choose line breaks when wrapping text to the line width.
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = WrapAlgorithmMode::Greedy,
+        global = true
+    )]
+    pub wrap_algorithm: WrapAlgorithmMode,
+
+    /// Whether wrapping text may break lines between CJK characters, where Typst renders the line break as a space.
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = CjkBreakMode::Never,
+        global = true
+    )]
+    pub cjk_break: CjkBreakMode,
+
+    /// Language of the text, such as `en` or `de`, which chooses the abbreviations that do not end sentences.
+    #[arg(long, default_value = "en", value_name = "LOCALE", global = true)]
+    pub sentence_locale: String,
+
+    /// An additional abbreviation, such as `Thm.`, whose period does not end a sentence.
+    #[arg(long = "sentence-abbreviation", value_name = "ABBR", global = true)]
+    pub sentence_abbreviations: Vec<String>,
+
+    /// When to add trailing commas to multi-line lists.
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = TrailingCommaMode::AlwaysWhenMultiline,
+        global = true
+    )]
+    pub trailing_comma: TrailingCommaMode,
+
+    /// Keep lists that end with a trailing comma expanded, and fold other lists if they fit.
+    #[arg(long, default_value_t = false, global = true)]
+    pub magic_trailing_comma: bool,
+
+    /// Align `=` of consecutive let bindings and `:` of named args and dict entries on their own lines.
+    #[arg(long, default_value_t = false, global = true)]
+    pub align_assignments: bool,
+
+    /// Align trailing line comments on consecutive lines in one column.
+    #[arg(long, default_value_t = false, global = true)]
+    pub align_trailing_comments: bool,
+
+    /// How to align cells within the columns of tables and grids.
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = TableAlignMode::None,
+        global = true
+    )]
+    pub table_align: TableAlignMode,
+
+    /// Format calls to the function like tables, given as a name or dotted path, optionally followed by `:` and the columns argument.
+    #[arg(long = "table-function", value_name = "NAME[:COLUMNS]", global = true)]
+    pub table_functions: Vec<String>,
+
+    /// Break block equations that exceed the line width before relation operators and then before `+` and `-`.
+    #[arg(long, default_value_t = false, global = true)]
+    pub break_long_equations: bool,
+
+    /// Normalize spaces in math around operators, before primes and inside delimiters.
+    #[arg(long, default_value_t = false, global = true)]
+    pub math_spacing: bool,
+
+    /// How to spell symbols that have both a shorthand and a name, such as `->` and `arrow.r`.
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = SymbolStyleMode::Preserve,
+        global = true
+    )]
+    pub symbol_style: SymbolStyleMode,
+
+    /// How to write fractions in inline equations.
+    #[arg(
+        long,
+        value_enum,
+        value_name = "STYLE",
+        default_value_t = FracStyleMode::Preserve,
+        global = true
+    )]
+    pub inline_frac_style: FracStyleMode,
+
+    /// How to write fractions in block equations.
+    #[arg(
+        long,
+        value_enum,
+        value_name = "STYLE",
+        default_value_t = FracStyleMode::Preserve,
+        global = true
+    )]
+    pub block_frac_style: FracStyleMode,
 }
 
 /// Text wrapping mode for CLI
@@ -114,6 +253,78 @@ pub enum WrapTextMode {
     Fill,
     /// Place each sentence on its own line
     Sentence,
+    /// Place each sentence on its own line and break long sentences at clauses
+    Clause,
+}
+
+/// Line breaking algorithm for CLI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum WrapAlgorithmMode {
+    /// Put as many words as fit on each line (default)
+    #[default]
+    Greedy,
+    /// Choose the breaks of each paragraph to minimize raggedness
+    Optimal,
+}
+
+/// CJK line breaking for CLI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum CjkBreakMode {
+    /// Only break text at spaces (default)
+    #[default]
+    Never,
+    /// Also break between CJK characters
+    Allow,
+}
+
+/// Trailing comma policy for CLI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum TrailingCommaMode {
+    /// Add a trailing comma when the list spans multiple lines (default)
+    #[default]
+    AlwaysWhenMultiline,
+    /// Never add a trailing comma
+    Never,
+    /// Keep a trailing comma in multi-line lists only where the source has one
+    Preserve,
+}
+
+/// Table cell alignment for CLI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum TableAlignMode {
+    /// Do not pad cells (default)
+    #[default]
+    None,
+    /// Pad cells after their content
+    Left,
+    /// Pad cells before their content
+    Right,
+    /// Pad cells evenly on both sides
+    Center,
+}
+
+/// Symbol spelling for CLI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum SymbolStyleMode {
+    /// Keep symbols as written (default)
+    #[default]
+    Preserve,
+    /// Write symbols in math as shorthands
+    Shorthand,
+    /// Write symbols by name
+    Named,
+}
+
+/// Fraction spelling for CLI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum FracStyleMode {
+    /// Keep fractions as written (default)
+    #[default]
+    Preserve,
+    /// Write fractions with a slash, as `a/b`
+    Slash,
+    /// Write fractions as calls, as `frac(a, b)`
+    Call,
 }
 
 #[derive(Args)]
@@ -0,0 +1,84 @@
+//! Collects the local Typst files reachable from an entrypoint through `#import` and `#include`.
+
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Result, bail};
+use log::warn;
+use typst_syntax::{
+    SyntaxNode,
+    ast::{Expr, ModuleImport, ModuleInclude},
+};
+
+use crate::fs;
+
+/// Resolves every local `.typ` file reachable from `entry`, including `entry` itself.
+///
+/// Only imports and includes whose source is a string literal are followed.
+/// Package imports (`@preview/...`) are ignored, and missing files are reported as warnings.
+/// Paths starting with `/` are resolved against the directory of the entry file,
+/// which is the default project root of Typst.
+pub fn resolve_entry_files(entry: &Path) -> Result<Vec<PathBuf>> {
+    let entry = fs::normalize_path(entry);
+    if !entry.is_file() {
+        bail!("entry file {} does not exist", fs::relativize_path(&entry));
+    }
+    let root = entry.parent().map(Path::to_path_buf).unwrap_or_default();
+
+    let mut visited = HashSet::from([entry.clone()]);
+    let mut files = vec![];
+    let mut pending = vec![entry];
+    while let Some(path) = pending.pop() {
+        // Read failures are reported later when the file is formatted.
+        let deps = std::fs::read_to_string(&path)
+            .map(|text| {
+                let mut deps = vec![];
+                collect_dependencies(&typst_syntax::parse(&text), &mut deps);
+                deps
+            })
+            .unwrap_or_default();
+        let dir = path.parent().unwrap_or(&root);
+        for dep in deps {
+            let resolved = fs::normalize_path(match dep.strip_prefix('/') {
+                Some(rooted) => root.join(rooted),
+                None => dir.join(&dep),
+            });
+            if resolved.extension() != Some("typ".as_ref()) || !visited.insert(resolved.clone()) {
+                continue;
+            }
+            if resolved.is_file() {
+                pending.push(resolved);
+            } else {
+                warn!(
+                    "File not found: {} (referenced from {})",
+                    fs::relativize_path(&resolved),
+                    fs::relativize_path(&path)
+                );
+            }
+        }
+        files.push(path);
+    }
+    files.sort_unstable();
+    Ok(files)
+}
+
+/// Collects the string-literal sources of all imports and includes in `node`,
+/// skipping package imports.
+fn collect_dependencies(node: &SyntaxNode, deps: &mut Vec<String>) {
+    let source = if let Some(import) = node.cast::<ModuleImport>() {
+        Some(import.source())
+    } else {
+        node.cast::<ModuleInclude>().map(|include| include.source())
+    };
+    if let Some(Expr::Str(path)) = source {
+        let path = path.get();
+        if !path.starts_with('@') {
+            deps.push(path.to_string());
+        }
+    }
+    for child in node.children() {
+        collect_dependencies(child, deps);
+    }
+}
@@ -10,8 +10,8 @@ use std::{
 
 use anyhow::{Context, Result, bail};
 use itertools::Itertools;
-use log::{debug, error, info, warn};
-use typst_syntax::Source;
+use log::{Level, debug, error, info, log_enabled, warn};
+use typst_syntax::{Source, Span};
 use typstyle_core::{Config, Typstyle, format_ast};
 use walkdir::{DirEntry, WalkDir};
 
@@ -19,6 +19,7 @@ use crate::{
     ExitStatus,
     cli::{CliArguments, DebugArgs, StyleArgs},
     diff::SourceDiff,
+    entry::resolve_entry_files,
     fs,
 };
 
@@ -46,19 +47,69 @@ impl FormatMode {
 
 impl StyleArgs {
     pub fn to_config(&self) -> Config {
-        use typstyle_core::WrapMode;
+        use typstyle_core::{
+            CjkBreak, FracStyle, SymbolStyle, TableAlign, TrailingComma, WrapAlgorithm, WrapMode,
+        };
 
-        use crate::cli::WrapTextMode;
+        use crate::cli::{
+            CjkBreakMode, FracStyleMode, SymbolStyleMode, TableAlignMode, TrailingCommaMode,
+            WrapAlgorithmMode, WrapTextMode,
+        };
 
         Config {
             max_width: self.line_width,
             tab_spaces: self.indent_width,
             reorder_import_items: !self.no_reorder_import_items,
+            reorder_imports: self.reorder_imports,
+            merge_imports: self.merge_imports,
             wrap_mode: match self.wrap_text {
                 WrapTextMode::None => WrapMode::None,
                 WrapTextMode::Fill => WrapMode::Fill,
                 WrapTextMode::Sentence => WrapMode::Sentence,
+                WrapTextMode::Clause => WrapMode::Clause,
             },
+            trailing_comma: match self.trailing_comma {
+                TrailingCommaMode::AlwaysWhenMultiline => TrailingComma::AlwaysWhenMultiline,
+                TrailingCommaMode::Never => TrailingComma::Never,
+                TrailingCommaMode::Preserve => TrailingComma::Preserve,
+            },
+            magic_trailing_comma: self.magic_trailing_comma,
+            align_assignments: self.align_assignments,
+            align_trailing_comments: self.align_trailing_comments,
+            table_align: match self.table_align {
+                TableAlignMode::None => TableAlign::None,
+                TableAlignMode::Left => TableAlign::Left,
+                TableAlignMode::Right => TableAlign::Right,
+                TableAlignMode::Center => TableAlign::Center,
+            },
+            table_functions: self.table_functions.clone(),
+            break_long_equations: self.break_long_equations,
+            math_spacing: self.math_spacing,
+            symbol_style: match self.symbol_style {
+                SymbolStyleMode::Preserve => SymbolStyle::Preserve,
+                SymbolStyleMode::Shorthand => SymbolStyle::Shorthand,
+                SymbolStyleMode::Named => SymbolStyle::Named,
+            },
+            inline_frac_style: match self.inline_frac_style {
+                FracStyleMode::Preserve => FracStyle::Preserve,
+                FracStyleMode::Slash => FracStyle::Slash,
+                FracStyleMode::Call => FracStyle::Call,
+            },
+            block_frac_style: match self.block_frac_style {
+                FracStyleMode::Preserve => FracStyle::Preserve,
+                FracStyleMode::Slash => FracStyle::Slash,
+                FracStyleMode::Call => FracStyle::Call,
+            },
+            wrap_algorithm: match self.wrap_algorithm {
+                WrapAlgorithmMode::Greedy => WrapAlgorithm::Greedy,
+                WrapAlgorithmMode::Optimal => WrapAlgorithm::Optimal,
+            },
+            cjk_break: match self.cjk_break {
+                CjkBreakMode::Never => CjkBreak::Never,
+                CjkBreakMode::Allow => CjkBreak::Allow,
+            },
+            sentence_locale: self.sentence_locale.clone(),
+            sentence_abbreviations: self.sentence_abbreviations.clone(),
             ..Default::default()
         }
     }
@@ -83,7 +134,10 @@ pub fn format(args: &CliArguments) -> Result<ExitStatus> {
     let mut summary = Summary::default();
 
     let mode = FormatMode::from_cli(args);
-    let paths = resolve_typst_files(&args.input);
+    let paths = match &args.entry {
+        Some(entry) => resolve_entry_files(entry)?,
+        None => resolve_typst_files(&args.input),
+    };
     if paths.is_empty() {
         warn!("No Typst files found under the given path(s).");
         return Ok(ExitStatus::Success);
@@ -170,7 +224,7 @@ fn format_one(
     let use_stdout = !args.inplace && !args.check && !args.diff;
     let unformatted = get_input(input)?;
 
-    let res = format_debug(&unformatted, typstyle, &args.debug);
+    let res = format_debug(input, &unformatted, typstyle, &args.debug);
     match &res {
         FormatResult::Formatted(res) => {
             if args.inplace {
@@ -217,7 +271,12 @@ enum FormatResult {
     Erroneous,
 }
 
-fn format_debug(content: &str, typstyle: &Typstyle, args: &DebugArgs) -> FormatResult {
+fn format_debug(
+    input: Option<&Path>,
+    content: &str,
+    typstyle: &Typstyle,
+    args: &DebugArgs,
+) -> FormatResult {
     let source = Source::detached(content);
     let root = source.root();
     if args.ast {
@@ -225,7 +284,7 @@ fn format_debug(content: &str, typstyle: &Typstyle, args: &DebugArgs) -> FormatR
     }
 
     let start_time = Instant::now();
-    let f = typstyle.format_source(source);
+    let f = typstyle.format_source(source.clone());
     if args.pretty_doc {
         match f.render_ir() {
             Ok(ir) => println!("{ir}"),
@@ -239,6 +298,16 @@ fn format_debug(content: &str, typstyle: &Typstyle, args: &DebugArgs) -> FormatR
     if args.timing {
         println!("Formatting completed in {:?}", start_time.elapsed());
     }
+    // Skipped rewrites are only reported with `--verbose`, but go to stderr like other warnings,
+    // so that they never mix with the formatted output.
+    if log_enabled!(Level::Debug) {
+        for warning in f.warnings() {
+            warn!(
+                "{}: {warning}",
+                describe_location(input, &source, warning.span)
+            );
+        }
+    }
 
     // Compare `res` with `content` to perform CI checks
     if res != content {
@@ -248,6 +317,20 @@ fn format_debug(content: &str, typstyle: &Typstyle, args: &DebugArgs) -> FormatR
     }
 }
 
+/// Locates a span as `path:line:column`.
+fn describe_location(input: Option<&Path>, source: &Source, span: Span) -> String {
+    let path = input.map_or_else(|| "<stdin>".to_string(), fs::relativize_path);
+    let Some(range) = source.range(span) else {
+        return path;
+    };
+    let lines = source.lines();
+    let line = lines.byte_to_line(range.start).map_or(0, |line| line + 1);
+    let column = lines
+        .byte_to_column(range.start)
+        .map_or(0, |column| column + 1);
+    format!("{path}:{line}:{column}")
+}
+
 fn get_input(input: Option<&Path>) -> Result<String> {
     match input {
         Some(path) => std::fs::read_to_string(path)
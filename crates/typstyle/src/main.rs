@@ -1,8 +1,11 @@
 mod cli;
 mod diff;
+mod entry;
 mod fmt;
 mod fs;
 mod logging;
+#[cfg(feature = "verify")]
+mod verify;
 
 use std::{io::Write, process::ExitCode};
 
@@ -59,9 +62,10 @@ fn main() -> ExitCode {
 }
 
 fn execute(args: CliArguments) -> Result<ExitStatus> {
-    #[cfg(feature = "completion")]
+    #[cfg(any(feature = "completion", feature = "verify"))]
     if let Some(command) = &args.command {
         match command {
+            #[cfg(feature = "completion")]
             cli::Command::Completions { shell } => {
                 use clap::CommandFactory;
 
@@ -74,10 +78,14 @@ fn execute(args: CliArguments) -> Result<ExitStatus> {
 
                 return Ok(ExitStatus::Success);
             }
+            #[cfg(feature = "verify")]
+            cli::Command::Verify(verify_args) => {
+                return verify::verify(verify_args, &args.style);
+            }
         }
     }
 
-    if args.input.is_empty() {
+    if args.input.is_empty() && args.entry.is_none() {
         format_stdin(&args)
     } else {
         format(&args)
@@ -0,0 +1,91 @@
+//! Verifies that formatting a project does not change the document it compiles to.
+
+use std::time::Instant;
+
+use anyhow::Result;
+use log::{error, info};
+use typstyle_consistency::{
+    CheckingOptions, CompileTarget, ErrorSink, FormattedSources, FormatterHarness, HarnessOptions,
+};
+use typstyle_core::{Error, Typstyle};
+
+use crate::{
+    ExitStatus,
+    cli::{StyleArgs, VerifyArgs},
+    fs,
+};
+
+/// Formats every `.typ` file under the project root in memory, then compiles the entrypoint
+/// before and after formatting and compares the evaluated content, pages and rendered pixels.
+///
+/// Files on disk are never modified. Sources with syntax errors are left unformatted.
+pub fn verify(args: &VerifyArgs, style: &StyleArgs) -> Result<ExitStatus> {
+    let root = fs::normalize_path(&args.root);
+    let config = style.to_config();
+    let config_repr = format!("{config:#?}");
+    let typstyle = Typstyle::new(config);
+
+    let start_time = Instant::now();
+    let mut harness = FormatterHarness::with_options(
+        String::new(),
+        root.clone(),
+        HarnessOptions {
+            font_paths: args.font_paths.iter().map(fs::normalize_path).collect(),
+            no_system_fonts: args.ignore_system_fonts,
+        },
+    )?;
+    harness.add_all_files(&root, &Default::default())?;
+    if args.report.is_some() {
+        harness.enable_html_report();
+    }
+    let file_count = harness.formattable_count();
+
+    let mut err_sink = ErrorSink::new(format!("verifying `{}`", fs::relativize_path(&root)));
+    let base_world = harness.snapshot();
+    let formatted = FormattedSources {
+        name: "formatted".to_string(),
+        sources: harness.format(
+            &base_world,
+            |source| match typstyle.format_source(source.clone()).render() {
+                Ok(res) => Ok(res),
+                Err(Error::SyntaxError) => Ok(source.text().to_string()),
+                Err(e) => Err(e.into()),
+            },
+            &mut err_sink,
+        )?,
+        config: config_repr,
+    };
+
+    let options = CheckingOptions {
+        expect_compile_success: true,
+        strict_content_equality: true,
+        target: if args.html {
+            CompileTarget::Html
+        } else {
+            CompileTarget::Paged
+        },
+        ..Default::default()
+    };
+    harness.compile_and_compare([formatted].iter(), &args.entry, options, &mut err_sink)?;
+
+    if let (Some(path), Some(report)) = (&args.report, harness.html_report())
+        && !report.is_empty()
+    {
+        report.write(path)?;
+        info!(
+            "Wrote report to {}",
+            fs::relativize_path(fs::normalize_path(path))
+        );
+    }
+
+    if err_sink.is_ok() {
+        info!(
+            "No differences found after formatting {file_count} files in {:?}",
+            start_time.elapsed()
+        );
+        Ok(ExitStatus::Success)
+    } else {
+        error!("{err_sink}");
+        Ok(ExitStatus::Failure)
+    }
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use common::{Workspace, typstyle_cmd_snapshot};
+
+#[test]
+fn test_entry_reachable() {
+    let mut space = Workspace::new();
+    space.write_tracked(
+        "main.typ",
+        "#import \"@preview/example:0.1.0\": *\n#import  \"lib/utils.typ\": helper\n#include  \"chapters/intro.typ\"",
+    );
+    space.write_tracked(
+        "chapters/intro.typ",
+        "#import \"../lib/utils.typ\": helper\n#include  \"/chapters/outro.typ\"",
+    );
+    space.write_tracked("chapters/outro.typ", "#let outro  =  1");
+    space.write_tracked("lib/utils.typ", "#let helper  =  0");
+    space.write_tracked("drafts/unused.typ", "#let unused  =  2");
+
+    typstyle_cmd_snapshot!(space.cli().args(["--entry", "main.typ", "-i", "-v"]), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Successfully formatted 4 files (0 unchanged) in [DURATION]
+
+    ----- stderr -----
+    ");
+
+    assert_eq!(
+        space.read_string("main.typ"),
+        "#import \"@preview/example:0.1.0\": *\n#import \"lib/utils.typ\": helper\n#include \"chapters/intro.typ\"\n"
+    );
+    assert_eq!(
+        space.read_string("chapters/intro.typ"),
+        "#import \"../lib/utils.typ\": helper\n#include \"/chapters/outro.typ\"\n"
+    );
+    assert_eq!(space.read_string("chapters/outro.typ"), "#let outro = 1\n");
+    assert_eq!(space.read_string("lib/utils.typ"), "#let helper = 0\n");
+    assert!(space.is_unmodified("drafts/unused.typ"));
+}
+
+#[test]
+fn test_entry_missing_dependency() {
+    let mut space = Workspace::new();
+    space.write_tracked("main.typ", "#include  \"missing.typ\"");
+
+    typstyle_cmd_snapshot!(space.cli().args(["--entry", "main.typ", "--check"]), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    Would reformat: main.typ
+
+    ----- stderr -----
+    warn: File not found: missing.typ (referenced from main.typ)
+    ");
+
+    assert!(space.all_unmodified());
+}
@@ -163,3 +163,20 @@ fn test_stdin_debug_ast() {
     ----- stderr -----
     "##);
 }
+
+#[test]
+fn test_stdin_verbose_warnings() {
+    let space = Workspace::new();
+
+    let stdin = r#"#import "a.typ": y, x, y as x"#;
+
+    typstyle_cmd_snapshot!(space.cli().args(["--verbose"]).pass_stdin(stdin), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    #import "a.typ": y, x, y as x
+
+    ----- stderr -----
+    warn: <stdin>:1:2: skipped import sorting: the items bind the same name more than once
+    "#);
+}
@@ -50,6 +50,230 @@ fn test_reorder_import_items() {
     "#);
 }
 
+#[test]
+fn test_trailing_comma() {
+    let space = Workspace::new();
+
+    let stdin = "#let a = (
+  1,
+  2
+)
+#let b = (
+  x: 1,
+  y: 2,
+)
+#let c = (1,)";
+
+    typstyle_cmd_snapshot!(space.cli().pass_stdin(stdin), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    #let a = (
+      1,
+      2,
+    )
+    #let b = (
+      x: 1,
+      y: 2,
+    )
+    #let c = (1,)
+
+    ----- stderr -----
+    ");
+    typstyle_cmd_snapshot!(space.cli().args(["--trailing-comma=never"]).pass_stdin(stdin), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    #let a = (
+      1,
+      2
+    )
+    #let b = (
+      x: 1,
+      y: 2
+    )
+    #let c = (1,)
+
+    ----- stderr -----
+    ");
+    typstyle_cmd_snapshot!(space.cli().args(["--trailing-comma=preserve"]).pass_stdin(stdin), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    #let a = (
+      1,
+      2
+    )
+    #let b = (
+      x: 1,
+      y: 2,
+    )
+    #let c = (1,)
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn test_magic_trailing_comma() {
+    let space = Workspace::new();
+
+    let stdin = "#let a = (1, 2,)
+#let b = (
+  1,
+  2
+)";
+
+    typstyle_cmd_snapshot!(space.cli().args(["--magic-trailing-comma"]).pass_stdin(stdin), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    #let a = (
+      1,
+      2,
+    )
+    #let b = (1, 2)
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn test_align_assignments() {
+    let space = Workspace::new();
+
+    let stdin = "#let x = 1
+#let width = 10pt
+#rect(
+  fill: red,
+  stroke: blue,
+)";
+
+    typstyle_cmd_snapshot!(space.cli().args(["--align-assignments"]).pass_stdin(stdin), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    #let x     = 1
+    #let width = 10pt
+    #rect(
+      fill:   red,
+      stroke: blue,
+    )
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn test_align_trailing_comments() {
+    let space = Workspace::new();
+
+    let stdin = "#let x = 1 // one
+#let yyy = 2 // two";
+
+    typstyle_cmd_snapshot!(space.cli().args(["--align-trailing-comments"]).pass_stdin(stdin), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    #let x = 1   // one
+    #let yyy = 2 // two
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn test_table_align() {
+    let space = Workspace::new();
+
+    let stdin = "#table(
+  columns: 2,
+  [a], [1],
+  [bbb], [100],
+)";
+
+    typstyle_cmd_snapshot!(space.cli().args(["--table-align", "right"]).pass_stdin(stdin), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    #table(
+      columns: 2,
+        [a],   [1],
+      [bbb], [100],
+    )
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn test_break_long_equations() {
+    let space = Workspace::new();
+
+    let stdin = "$ f(x) = a + b + c = d + e + f $";
+
+    typstyle_cmd_snapshot!(space.cli().args(["--break-long-equations", "-l", "20"]).pass_stdin(stdin), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    $
+      f(x)
+        = a + b + c
+        = d + e + f
+    $
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn test_math_spacing() {
+    let space = Workspace::new();
+
+    let stdin = "$ x=a b+( y^2 ) $";
+
+    typstyle_cmd_snapshot!(space.cli().args(["--math-spacing"]).pass_stdin(stdin), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    $ x = a b + (y^2) $
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn test_symbol_style() {
+    let space = Workspace::new();
+
+    let stdin = "$ a->b != c $ and 1--2";
+
+    typstyle_cmd_snapshot!(space.cli().args(["--symbol-style", "named"]).pass_stdin(stdin), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    $ a arrow.r b eq.not c $ and 1#sym.dash.en;2
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn test_frac_style() {
+    let space = Workspace::new();
+
+    let stdin = "$frac(a + b, 2)$ $ (a + b)/2 $";
+
+    typstyle_cmd_snapshot!(space.cli().args(["--inline-frac-style", "slash", "--block-frac-style", "call"]).pass_stdin(stdin), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    $(a + b)/2$ $ frac(a + b, 2) $
+
+    ----- stderr -----
+    ");
+}
+
 #[test]
 fn test_wrap_text() {
     let space = Workspace::new();
@@ -109,6 +333,100 @@ fn test_wrap_text_modes() {
     First sentence has extra spaces and enough words to wrap.
     Second sentence follows.
 
+    ----- stderr -----
+    ");
+    typstyle_cmd_snapshot!(space.cli().args(["-c=34", "--wrap-text=clause"]).pass_stdin(stdin), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    First sentence has extra spaces
+    and enough words to wrap.
+    Second sentence follows.
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn test_wrap_algorithm() {
+    let space = Workspace::new();
+
+    let stdin = "Optimal filling keeps the lines of a paragraph at similar lengths, so that `inline code` and $x + y$ do not leave a ragged edge behind them.";
+
+    typstyle_cmd_snapshot!(space.cli().args(["-c=36", "--wrap-text"]).pass_stdin(stdin), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Optimal filling keeps the lines of a
+    paragraph at similar lengths, so
+    that `inline code` and $x + y$ do
+    not leave a ragged edge behind them.
+
+    ----- stderr -----
+    ");
+    typstyle_cmd_snapshot!(space.cli().args(["-c=36", "--wrap-text", "--wrap-algorithm=optimal"]).pass_stdin(stdin), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Optimal filling keeps the lines of
+    a paragraph at similar lengths, so
+    that `inline code` and $x + y$ do
+    not leave a ragged edge behind them.
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn test_cjk_break() {
+    let space = Workspace::new();
+
+    let stdin = "中文段落需要在汉字之间换行。";
+
+    typstyle_cmd_snapshot!(space.cli().args(["-c=10", "--wrap-text"]).pass_stdin(stdin), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    中文段落需要在汉字之间换行。
+
+    ----- stderr -----
+    ");
+    typstyle_cmd_snapshot!(space.cli().args(["-c=10", "--wrap-text", "--cjk-break=allow"]).pass_stdin(stdin), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    中文段落需
+    要在汉字之
+    间换行。
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn test_sentence_abbreviations() {
+    let space = Workspace::new();
+
+    let stdin = "Siehe vgl. Abb. 2 in Thm. Zwei. Weiter.";
+
+    typstyle_cmd_snapshot!(space.cli().args(["--wrap-text=sentence"]).pass_stdin(stdin), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Siehe vgl.
+    Abb. 2 in Thm.
+    Zwei.
+    Weiter.
+
+    ----- stderr -----
+    ");
+    typstyle_cmd_snapshot!(space.cli().args(["--wrap-text=sentence", "--sentence-locale=de", "--sentence-abbreviation=Thm."]).pass_stdin(stdin), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Siehe vgl. Abb. 2 in Thm. Zwei.
+    Weiter.
+
     ----- stderr -----
     ");
 }
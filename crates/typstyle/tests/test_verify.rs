@@ -0,0 +1,26 @@
+#![cfg(feature = "verify")]
+
+mod common;
+
+use common::{Workspace, typstyle_cmd_snapshot};
+
+#[test]
+fn test_verify_unchanged() {
+    let mut space = Workspace::new();
+    space.write_tracked(
+        "main.typ",
+        "#let title  =  [Hello]\n= #title\n#include  \"chapters/intro.typ\"",
+    );
+    space.write_tracked("chapters/intro.typ", "Some   *text*.");
+
+    typstyle_cmd_snapshot!(space.cli().args(["verify", ".", "--entry", "main.typ", "--ignore-system-fonts"]), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    No differences found after formatting 2 files in [DURATION]
+
+    ----- stderr -----
+    ");
+
+    assert!(space.all_unmodified());
+}
@@ -32,6 +32,15 @@ typstyle file1.typ file2.typ
 typstyle src/ docs/
 ```
 
+== Entrypoint
+
+```bash
+# Format only the files reachable from `main.typ` via `#import` and `#include`
+typstyle --entry main.typ -i
+```
+
+Only imports and includes with a string-literal path are followed, and package imports such as `@preview/...` are skipped. A warning is printed for each referenced file that does not exist.
+
 = Main Options
 
 == Format Control
@@ -81,10 +90,87 @@ typstyle --indent-width 4 file.typ
 typstyle --wrap-text file.typ
 typstyle --wrap-text=fill file.typ
 
+# Choose breaks that keep the lengths of the lines in each paragraph even
+typstyle --wrap-text --wrap-algorithm optimal file.typ
+
+# Also break lines between CJK characters, although Typst renders such line breaks as spaces
+typstyle --wrap-text --cjk-break allow file.typ
+
 # Place each sentence on its own line
 typstyle --wrap-text=sentence file.typ
+
+# Place each sentence on its own line, breaking long sentences after clause punctuation
+typstyle --wrap-text=clause file.typ
+
+# Use the German abbreviations, and do not end sentences after `Thm.` and `Lem.`
+typstyle --wrap-text=sentence --sentence-locale de --sentence-abbreviation Thm. --sentence-abbreviation Lem. file.typ
+```
+
+=== Trailing Commas
+
+```bash
+# Add trailing commas to multi-line lists (default)
+typstyle --trailing-comma always-when-multiline file.typ
+
+# Never add trailing commas
+typstyle --trailing-comma never file.typ
+
+# Keep trailing commas in multi-line lists only where the source has them
+typstyle --trailing-comma preserve file.typ
+```
+
+Commas required by the semantics, such as in the single-element array `(a,)`, are always kept.
+
+```bash
+# Keep lists with a trailing comma expanded, and fold other lists if they fit
+typstyle --magic-trailing-comma file.typ
+```
+
+=== Alignment
+
+```bash
+# Align `=` of consecutive let bindings and `:` of named args and dict entries
+typstyle --align-assignments file.typ
+
+# Align trailing line comments on consecutive lines
+typstyle --align-trailing-comments file.typ
+
+# Pad table and grid cells into columns: none (default), left, right or center
+typstyle --table-align right file.typ
+
+# Format calls to your own table helpers like tables, optionally naming the columns argument
+typstyle --table-function my-table --table-function tablex.tablex:cols file.typ
+```
+
+=== Math
+
+```bash
+# Break block equations that exceed the line width before relation operators, then before `+` and `-`
+typstyle --break-long-equations file.typ
+
+# Normalize spaces around operators, before primes and inside delimiters in math
+typstyle --math-spacing file.typ
+
+# Write math shorthands such as `->` and `!=` by name, as `arrow.r` and `eq.not`
+typstyle --symbol-style named file.typ
+
+# Write fractions as `a/b` in inline equations and as `frac(a, b)` in block equations
+typstyle --inline-frac-style slash --block-frac-style call file.typ
 ```
 
+= Verifying Formatting
+
+When built with the `verify` cargo feature, the `verify` subcommand checks that formatting a project does not change the document it compiles to. Every `.typ` file under the project root is formatted in memory, and the entrypoint is compiled before and after formatting. The evaluated content, the pages and the rendered pixels are then compared. Files on disk are never modified.
+
+```bash
+cargo install typstyle --locked --features verify
+
+# Verify with the same style options as used for formatting
+typstyle verify . --entry main.typ -l 100
+```
+
+Local fonts and packages are used for compilation. Pass `--font-path` to search additional font directories, or `--ignore-system-fonts` to skip installed fonts. Changed pages and content are printed, and the command exits with a non-zero status code. For documents published through Typst's HTML export, pass `--html` to compare the normalized HTML output instead of the pages. Pass `--report <file>` to write a self-contained HTML report with the renders before and after formatting, a diff overlay of each differing page, and the source diffs.
+
 = Debug Options
 
 == AST Output
@@ -113,3 +199,9 @@ typstyle --timing file.typ
 = Logging Options
 
 See CLI help.
+
+With `--verbose`, Typstyle also reports the requested rewrites it skipped on stderr, along with their locations and reasons, such as import items that are left unsorted because they bind the same name twice, or tables that are not reflowed because their columns cannot be determined.
+
+```bash
+typstyle --verbose file.typ
+```
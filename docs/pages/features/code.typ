@@ -105,6 +105,93 @@ Typstyle preserves intentional blank lines in code and strips excessive ones, in
 )
 ```
 
+== Trailing Commas
+
+By default, Typstyle adds a trailing comma to every list-like structure that spans multiple lines, and removes it when the structure fits on one line.
+The `trailing_comma` option changes this policy for arguments, parameters, arrays, dictionaries, destructuring and import lists:
+- `always-when-multiline` (default): add a trailing comma when the list is expanded
+- `never`: never add a trailing comma
+- `preserve`: add a trailing comma to an expanded list only if the source has one
+
+Commas required by the semantics are always kept, such as in the single-element array ```typc (a,)```.
+
+```typst
+/// typstyle: trailing_comma=never
+#let a = (
+  1,
+  2,
+)
+#let b = (1,)
+```
+
+```typst
+/// typstyle: trailing_comma=preserve
+#let a = (
+  1,
+  2,
+)
+#let b = (
+  x: 1,
+  y: 2
+)
+```
+
+== Magic Trailing Comma
+
+With the `magic_trailing_comma` option, a trailing comma replaces flavor detection for arguments, parameters, arrays, dictionaries and destructuring.
+A list that ends with a trailing comma in the source stays expanded with one item per line, even if it fits.
+Other lists are folded if they fit, so removing the comma collapses the list again.
+Magic trailing commas are kept even with `trailing_comma=never`.
+
+```typst
+/// typstyle: magic_trailing_comma
+#let a = (1, 2,)
+#let b = (
+  1,
+  2
+)
+#f(red, stroke: blue,)
+```
+
+== Aligned Assignments
+
+With the `align_assignments` option, `=` is aligned across a run of consecutive single-line `let` bindings, and values are aligned after `:` across named arguments and dictionary entries laid out one per line, whether written so or broken to fit the maximum width.
+Widths are measured in Unicode display width.
+Blank lines, comments on their own lines and other statements end a run, while trailing comments do not.
+Bindings that span multiple lines are not aligned, nor is a run whose padded lines would exceed the maximum width.
+
+```typst
+/// typstyle: align_assignments
+#let x = 1
+#let width = 10pt
+#let f(a, b) = a + b
+
+#rect(
+  fill: red,
+  stroke: blue,
+  inset: 4pt,
+)
+#let d = (
+  "a": 1,
+  long-key: 2,
+)
+```
+
+== Aligned Trailing Comments
+
+With the `align_trailing_comments` option, trailing line comments on consecutive lines with the same indentation are aligned in one column.
+A comment is never moved past the line width, and comments that directly follow markup text without a space are left as they are.
+
+```typst
+/// typstyle: align_trailing_comments
+#let f(
+  a, // first
+  long-name, // second
+) = a
+#let x = 1 // one
+#let yyy = 2 // two
+```
+
 = Code Block Structure
 
 == Single-Statement Blocks
@@ -291,6 +378,56 @@ Import items are sorted alphabetically by default:
 #import "module.typ": zebra,alpha,beta,gamma
 ```
 
+Comments move along with their items: a comment after an item on the same line stays after it, and a comment on its own line stays before the next item.
+Items are left unsorted only if a comment cannot be attached unambiguously, e.g., when it sits between two items on the same line.
+
+```typst
+#import "module.typ": (
+  zebra, // the last one
+  // the first one
+  alpha,
+  beta,
+)
+```
+
+== Statement Ordering
+
+With the `reorder_imports` option, consecutive import statements in markup are sorted by their sources.
+Package imports come first, then absolute paths, then relative paths, each sorted by path and version.
+Blank lines and comments split the imports into groups, which are sorted separately.
+
+```typst
+/// typstyle: reorder_imports
+#import "utils.typ": helper
+#import "@preview/fletcher:0.5.7" as fletcher
+#import "/template.typ": conf
+#import "@preview/cetz:0.4.0"
+
+#import "chapters/b.typ": b
+#import "chapters/a.typ": a
+```
+
+#callout.note[
+  Imports are only sorted if their sources are string literals and they bind distinct names, since the order decides which binding wins.
+  Names from wildcard imports are unknown, so sorting them can still change which binding wins.
+]
+
+== Merging Imports
+
+With the `merge_imports` option, consecutive imports of items from the same source in markup are merged into one import with the union of their items.
+Duplicated items are removed, and the items are sorted as usual.
+Imports that rename the module, use a wildcard, or would bind the same name twice are left as they are.
+Combined with `reorder_imports`, imports of the same source are first moved next to each other.
+
+```typst
+/// typstyle: merge_imports
+#import "utils.typ": b
+#import "utils.typ": a, b
+#import "utils.typ": *
+#import "theme.typ": colors
+#import "theme.typ": colors as palette
+```
+
 == Soft Wrapping
 
 Import statements use soft wrapping for long item lists, keeping them compact yet readable:
@@ -101,6 +101,35 @@ End
 ````
 
 
+== Semantic Line Breaks
+
+With `--wrap-text=clause`, Typstyle follows the #link("https://sembr.org/")[semantic line breaks] convention. Each sentence starts on its own line. When a sentence does not fit within the line width, it breaks after clause punctuation (`,`, `;`, `:` and `—`) before a clause that would overflow, and the words within a clause are filled as in `fill` mode:
+
+```typst
+/// typstyle: wrap_mode=clause, max_width=40
+Long sentences in academic prose, which tend to run on for a while, are easier to review when clauses start new lines. Short ones stay put.
+```
+
+== Abbreviations
+
+In `sentence` and `clause` modes, a period after a common abbreviation or after a single capital initial does not end a sentence, so "Dr. Smith", "e.g. this" and "J. Doe" stay together. The abbreviations depend on the language set with `--sentence-locale`, which supports `en` (default), `de` and `fr`. Other languages use the English abbreviations. Add your own with `--sentence-abbreviation`:
+
+```typst
+/// typstyle: wrap_mode=sentence, sentence_abbreviations=Thm.
+Dr. Smith and J. Doe proved it in Thm. Two of their paper. It was hard.
+```
+
+== Optimal Filling
+
+By default, `fill` mode puts as many words as fit on each line. With `--wrap-algorithm=optimal`, Typstyle instead chooses the breaks of each paragraph to keep the lengths of its lines even, like the Knuth–Plass algorithm of TeX. Inline code, equations, links and other nodes are never broken. Formatting the result again gives the same breaks:
+
+```typst
+/// typstyle: wrap_mode=fill, wrap_algorithm=optimal, max_width=36
+Optimal filling keeps the lines of a paragraph at similar lengths, so that `inline code` and $x + y$ do not leave a ragged edge behind them.
+```
+
+This applies to top-level paragraphs and the bodies of top-level list and enum items. Markup in content blocks and other nested markup is filled greedily.
+
 == Multilingual Text Support
 
 Typstyle measures Unicode width and will not break between words if no space exists in the original text.
@@ -115,3 +144,10 @@ Mixed CJK and Latin: Visit 访问 https://example.com/文档 for documentation.
 
 Multiple scripts: أهلاً بك في *타이프스트* เอกสาร with `inline code`.
 ```
+
+Typst renders a line break in the source as a space, even between CJK characters and after CJK punctuation such as `。` or `，`. No line break between CJK characters is invisible in the output, so there is no safe subset to take, and Chinese and Japanese text without spaces is not wrapped by default. With `--cjk-break=allow`, Typstyle also breaks lines between CJK characters where the Unicode line breaking rules allow it, so no line starts with closing punctuation such as `。`. Lines that break between CJK characters are joined without a space. Latin words and Hangul are never split:
+
+```typst
+/// typstyle: wrap_mode=fill, cjk_break=allow, max_width=40
+这是一个很长的中文段落，它没有任何空格，因此只有在允许于汉字之间换行时才会被折行。
+```
@@ -55,6 +55,85 @@ c&=sum_(i=1)^n x_i
 $
 ```
 
+= Breaking Long Equations
+
+Math equations are never broken by default. With the opt-in `break_long_equations` option (`--break-long-equations`), block equations that exceed the line width are broken before top-level relation operators such as `=`, `<=` and `arrow`, and then before `+` and `-`. Continuation lines get a hanging indent:
+
+```typst
+/// typstyle: break_long_equations, max_width=30
+$ E = m c^2 + (p c)^2 + k_1 x + k_2 x^2 = m_0 c^2 + e $
+```
+
+The breaks only replace spaces, which math mode ignores, so the rendered equation does not change. A `\` linebreak changes the output, so Typstyle never inserts one: a semantic break is only ever requested by the author writing `\`. Equations with align points, `\` linebreaks or comments keep their layout, and so do equations with linebreaks at other positions. Linebreaks before the operators above are laid out again, so formatting an already broken equation gives the same result.
+
+= Spacing
+
+Typst decides the spacing in math by the class of each operator rather than by the spaces in the source, so `a+b` and `a + b` look the same. With the opt-in `math_spacing` option (`--math-spacing`), Typstyle normalizes the source:
+
+- Binary and relation operators are surrounded by a single space.
+- Spaces before primes are removed.
+- Padding inside delimiters is trimmed.
+
+Spaces between identifiers, such as in `a b`, are kept, since `ab` would be a different identifier:
+
+```typst
+/// typstyle: math_spacing
+$ f ' (x)=a b+( x^2 ) $
+```
+
+Spaces around attachments are always removed.
+
+= Symbol Style
+
+Many symbols can be written either as a shorthand or by name, such as `->` and `arrow.r`, or `!=` and `eq.not`. The `symbol_style` option (`--symbol-style`) picks one spelling:
+
+- `preserve` (default) keeps symbols as written.
+- `shorthand` writes symbol names in math, with or without the `sym.` prefix, as shorthands.
+- `named` writes shorthands as symbol names. Spaces are added where a name would merge with its neighbors.
+
+```typst
+/// typstyle: symbol_style=named
+$ a->b, x<=y != z $
+```
+
+Only shorthands of more than one character are rewritten, so `-`, `*` and `~` are kept. The delimiter shorthands `[|`, `|]` and `||` are kept too, since Typst scales matching delimiters but not their names. Literal Unicode characters, such as `→`, `–` and `—`, are never rewritten in either direction. A name that the file binds itself, for example with `#let arrow = ...`, is not treated as a symbol, and neither is any name in a file with a wildcard import such as `#import "defs.typ": *`, since it may bind any name.
+
+In markup, `named` writes the shorthands `--`, `---`, `~`, `-?` and `...` as `#sym` names, closing them with `;` where the following text would continue the name. `shorthand` leaves markup alone: `#sym` names in markup are not turned back into shorthands.
+
+= Fractions
+
+A fraction can be written with a slash, as in `a/b`, or as a call, as in `frac(a, b)`. The `inline_frac_style` and `block_frac_style` options (`--inline-frac-style` and `--block-frac-style`) pick one spelling for inline and block equations:
+
+- `preserve` (default) keeps fractions as written.
+- `slash` writes calls to `frac` with two arguments as slashes.
+- `call` writes slashes as calls to `frac`.
+
+Typst drops the parentheses around the numerator and denominator of a slash, so they are dropped when converting to a call, and added when converting to a slash where the operand would otherwise extend beyond the fraction:
+
+```typst
+/// typstyle: inline_frac_style=slash, block_frac_style=call
+Inline $frac(a + b, 2)$ and block:
+$ (a + b)/2 = x^2/(y) $
+```
+
+A `frac` call is kept if a slash would take its neighbors into the operands, as in `2 frac(a, b)c`. Fractions with comments, `&` or `\` are kept as written.
+
+= Matrices and Cases
+
+When the arguments of `mat`, `vec` or `cases` already span multiple lines, Typstyle places each row or entry on its own line. The cells of a matrix are padded so that its columns line up, as long as the padded rows fit within the line width:
+
+```typst
+$ mat(
+  1, -2, 300;
+  40, 5, -6
+) $
+
+$ vec(1, 2,
+  3) $
+```
+
+Matrices whose cells contain `&`, `\`, comments or hashed expressions keep their original layout.
+
 = Comments in Math
 
 Typstyle can format math equations containing comments while preserving their meaning and proper placement:
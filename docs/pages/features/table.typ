@@ -55,6 +55,44 @@ When a table row cannot fit on a single line, each cell is placed on its own lin
 )
 ```
 
+The number of columns may also be computed from constants defined by top-level `let` bindings in the same file, and from pure builtins such as `range`, `calc.max`, `calc.min` and the array methods `len`, `map` and `rev`:
+
+```typst
+#let n = 3
+#table(columns: range(n).map(_ => 1fr), [a], [b], [c], [d], [e], [f])
+```
+
+A name bound more than once in the file, for example by a function parameter or a loop, is not treated as a constant. A builtin is not used if the file binds a name that shadows it.
+
+= Custom Table Functions
+
+Calls to `table` and `grid` are recognized by default.
+The `table_functions` option lists more functions that take cells as positional arguments, such as your own wrappers or functions from packages.
+Each entry is a name or a dotted path, matched against the callee as written, such as `my-table` or `tablex.tablex`.
+An entry may be followed by `:` and the name of the argument that gives the number of columns, which defaults to `columns`.
+In these functions, the special cells of tablex are also recognized: names ending in `x`, such as `hlinex` and `cellx`, as well as `colspan`, `rowspan` and their `x` variants.
+
+```typst
+/// typstyle: table_functions=(my-table)
+#my-table(columns: 2, [Name], [Age], [Hannes], [36], [Irma], [50])
+```
+
+= Column Alignment
+
+With the `table_align` option set to `left`, `right` or `center`, reflowed cells are padded to a common display width within each column, as Markdown table formatters do.
+A column is only padded if all its cells are single-line.
+Right alignment suits numeric columns.
+
+```typst
+/// typstyle: table_align=right
+#table(
+  columns: 3,
+  [Substance], [Subcritical °C], [Supercritical °C],
+  [Hydrochloric Acid], [12.0], [92.1],
+  [Sodium Myreth Sulfate], [16.6], [104],
+)
+```
+
 = Advanced Table Features
 
 Typstyle provides comprehensive support for complex table structures:
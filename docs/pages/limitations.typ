@@ -16,7 +16,7 @@ If you find that a comment is lost or the formatting result is unsatisfactory du
 
 Math mode is highly sensitive to spacing, and users may rely on precise spacing for visual effects. Therefore, Typstyle avoids changing spaces within math mode to ensure the rendered result is unchanged.
 
-Additionally, Typstyle will not convert spaces into line breaks (or vice versa) in math, as such changes can adversely affect the appearance of equations. We respect the user's intent regarding spaces and linebreaks.
+Additionally, Typstyle will not convert spaces into line breaks (or vice versa) in math, as such changes can adversely affect the appearance of equations. We respect the user's intent regarding spaces and linebreaks. The only exception is the opt-in `break_long_equations` option, which breaks long block equations at spaces around operators.
 
 = Tables
 
@@ -28,7 +28,7 @@ We fall back to a plain layout (structure preserved) in these cases:
 
 - The table contains a block comment or has no positional arguments.
 - It lacks a `columns` argument or uses spread arguments which possibly define columns.
-- The `columns` argument is not a simple constant expression.
+- The `columns` argument is not a simple constant expression. It may refer to constants from top-level `let` bindings in the same file, but not to imported values.
 
 = Compact Layout
 
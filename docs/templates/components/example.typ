@@ -41,6 +41,9 @@
             int(value-str)
           } else if value-str.match(regex("^\d*\.\d+$")) != none {
             float(value-str)
+          } else if value-str.starts-with("(") and value-str.ends-with(")") {
+            // Arrays like "(a b)"
+            value-str.slice(1, -1).split(" ").filter(s => s != "")
           } else {
             value-str // Keep as string
           }
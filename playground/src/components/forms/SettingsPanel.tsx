@@ -16,7 +16,24 @@ export function SettingsPanel({
   const indentWidthInputId = useId();
   const collapseMarkupSpacesId = useId();
   const reorderImportItemsId = useId();
+  const reorderImportsId = useId();
+  const mergeImportsId = useId();
   const wrapModeId = useId();
+  const wrapAlgorithmId = useId();
+  const cjkBreakId = useId();
+  const sentenceLocaleId = useId();
+  const sentenceAbbreviationsId = useId();
+  const trailingCommaId = useId();
+  const magicTrailingCommaId = useId();
+  const alignAssignmentsId = useId();
+  const alignTrailingCommentsId = useId();
+  const tableAlignId = useId();
+  const tableFunctionsId = useId();
+  const breakLongEquationsId = useId();
+  const mathSpacingId = useId();
+  const symbolStyleId = useId();
+  const inlineFracStyleId = useId();
+  const blockFracStyleId = useId();
 
   const lineWidthValues = [0, 20, 40, 60, 80, 100, 120];
 
@@ -150,6 +167,38 @@ export function SettingsPanel({
         />
       </div>
 
+      <div className="flex items-center justify-between w-full">
+        <label htmlFor={reorderImportsId}>Reorder Imports:</label>
+        <input
+          id={reorderImportsId}
+          type="checkbox"
+          className="checkbox"
+          checked={formatOptions.reorderImports}
+          onChange={(e) =>
+            setFormatOptions((prev) => ({
+              ...prev,
+              reorderImports: e.target.checked,
+            }))
+          }
+        />
+      </div>
+
+      <div className="flex items-center justify-between w-full">
+        <label htmlFor={mergeImportsId}>Merge Imports:</label>
+        <input
+          id={mergeImportsId}
+          type="checkbox"
+          className="checkbox"
+          checked={formatOptions.mergeImports}
+          onChange={(e) =>
+            setFormatOptions((prev) => ({
+              ...prev,
+              mergeImports: e.target.checked,
+            }))
+          }
+        />
+      </div>
+
       <div className="flex items-center justify-between w-full">
         <label htmlFor={wrapModeId}>Wrap Text:</label>
         <select
@@ -166,6 +215,270 @@ export function SettingsPanel({
           <option value="none">None</option>
           <option value="fill">Fill</option>
           <option value="sentence">Sentence</option>
+          <option value="clause">Clause</option>
+        </select>
+      </div>
+
+      <div className="flex items-center justify-between w-full">
+        <label htmlFor={wrapAlgorithmId}>Wrap Algorithm:</label>
+        <select
+          id={wrapAlgorithmId}
+          className="select"
+          value={formatOptions.wrapAlgorithm}
+          onChange={(e) =>
+            setFormatOptions((prev) => ({
+              ...prev,
+              wrapAlgorithm: e.target.value as FormatOptions["wrapAlgorithm"],
+            }))
+          }
+        >
+          <option value="greedy">Greedy</option>
+          <option value="optimal">Optimal</option>
+        </select>
+      </div>
+
+      <div className="flex items-center justify-between w-full">
+        <label htmlFor={cjkBreakId}>CJK Break:</label>
+        <select
+          id={cjkBreakId}
+          className="select"
+          value={formatOptions.cjkBreak}
+          onChange={(e) =>
+            setFormatOptions((prev) => ({
+              ...prev,
+              cjkBreak: e.target.value as FormatOptions["cjkBreak"],
+            }))
+          }
+        >
+          <option value="never">Never</option>
+          <option value="allow">Allow</option>
+        </select>
+      </div>
+
+      <div className="flex items-center justify-between w-full">
+        <label htmlFor={sentenceLocaleId}>Sentence Locale:</label>
+        <input
+          id={sentenceLocaleId}
+          type="text"
+          className="input w-40"
+          placeholder="en"
+          value={formatOptions.sentenceLocale}
+          onChange={(e) =>
+            setFormatOptions((prev) => ({
+              ...prev,
+              sentenceLocale: e.target.value,
+            }))
+          }
+        />
+      </div>
+
+      <div className="flex items-center justify-between w-full">
+        <label htmlFor={sentenceAbbreviationsId}>Sentence Abbreviations:</label>
+        <input
+          id={sentenceAbbreviationsId}
+          type="text"
+          className="input w-40"
+          placeholder="Thm., Lem."
+          value={formatOptions.sentenceAbbreviations}
+          onChange={(e) =>
+            setFormatOptions((prev) => ({
+              ...prev,
+              sentenceAbbreviations: e.target.value,
+            }))
+          }
+        />
+      </div>
+
+      <div className="flex items-center justify-between w-full">
+        <label htmlFor={trailingCommaId}>Trailing Comma:</label>
+        <select
+          id={trailingCommaId}
+          className="select"
+          value={formatOptions.trailingComma}
+          onChange={(e) =>
+            setFormatOptions((prev) => ({
+              ...prev,
+              trailingComma: e.target.value as FormatOptions["trailingComma"],
+            }))
+          }
+        >
+          <option value="always-when-multiline">When Multiline</option>
+          <option value="never">Never</option>
+          <option value="preserve">Preserve</option>
+        </select>
+      </div>
+
+      <div className="flex items-center justify-between w-full">
+        <label htmlFor={magicTrailingCommaId}>Magic Trailing Comma:</label>
+        <input
+          id={magicTrailingCommaId}
+          type="checkbox"
+          className="checkbox"
+          checked={formatOptions.magicTrailingComma}
+          onChange={(e) =>
+            setFormatOptions((prev) => ({
+              ...prev,
+              magicTrailingComma: e.target.checked,
+            }))
+          }
+        />
+      </div>
+
+      <div className="flex items-center justify-between w-full">
+        <label htmlFor={alignAssignmentsId}>Align Assignments:</label>
+        <input
+          id={alignAssignmentsId}
+          type="checkbox"
+          className="checkbox"
+          checked={formatOptions.alignAssignments}
+          onChange={(e) =>
+            setFormatOptions((prev) => ({
+              ...prev,
+              alignAssignments: e.target.checked,
+            }))
+          }
+        />
+      </div>
+
+      <div className="flex items-center justify-between w-full">
+        <label htmlFor={alignTrailingCommentsId}>Align Trailing Comments:</label>
+        <input
+          id={alignTrailingCommentsId}
+          type="checkbox"
+          className="checkbox"
+          checked={formatOptions.alignTrailingComments}
+          onChange={(e) =>
+            setFormatOptions((prev) => ({
+              ...prev,
+              alignTrailingComments: e.target.checked,
+            }))
+          }
+        />
+      </div>
+
+      <div className="flex items-center justify-between w-full">
+        <label htmlFor={tableAlignId}>Table Align:</label>
+        <select
+          id={tableAlignId}
+          className="select"
+          value={formatOptions.tableAlign}
+          onChange={(e) =>
+            setFormatOptions((prev) => ({
+              ...prev,
+              tableAlign: e.target.value as FormatOptions["tableAlign"],
+            }))
+          }
+        >
+          <option value="none">None</option>
+          <option value="left">Left</option>
+          <option value="right">Right</option>
+          <option value="center">Center</option>
+        </select>
+      </div>
+
+      <div className="flex items-center justify-between w-full">
+        <label htmlFor={tableFunctionsId}>Table Functions:</label>
+        <input
+          id={tableFunctionsId}
+          type="text"
+          className="input w-40"
+          placeholder="my-table, tablex.tablex"
+          value={formatOptions.tableFunctions}
+          onChange={(e) =>
+            setFormatOptions((prev) => ({
+              ...prev,
+              tableFunctions: e.target.value,
+            }))
+          }
+        />
+      </div>
+
+      <div className="flex items-center justify-between w-full">
+        <label htmlFor={breakLongEquationsId}>Break Long Equations:</label>
+        <input
+          id={breakLongEquationsId}
+          type="checkbox"
+          className="checkbox"
+          checked={formatOptions.breakLongEquations}
+          onChange={(e) =>
+            setFormatOptions((prev) => ({
+              ...prev,
+              breakLongEquations: e.target.checked,
+            }))
+          }
+        />
+      </div>
+
+      <div className="flex items-center justify-between w-full">
+        <label htmlFor={mathSpacingId}>Math Spacing:</label>
+        <input
+          id={mathSpacingId}
+          type="checkbox"
+          className="checkbox"
+          checked={formatOptions.mathSpacing}
+          onChange={(e) =>
+            setFormatOptions((prev) => ({
+              ...prev,
+              mathSpacing: e.target.checked,
+            }))
+          }
+        />
+      </div>
+
+      <div className="flex items-center justify-between w-full">
+        <label htmlFor={symbolStyleId}>Symbol Style:</label>
+        <select
+          id={symbolStyleId}
+          className="select"
+          value={formatOptions.symbolStyle}
+          onChange={(e) =>
+            setFormatOptions((prev) => ({
+              ...prev,
+              symbolStyle: e.target.value as FormatOptions["symbolStyle"],
+            }))
+          }
+        >
+          <option value="preserve">Preserve</option>
+          <option value="shorthand">Shorthand</option>
+          <option value="named">Named</option>
+        </select>
+      </div>
+
+      <div className="flex items-center justify-between w-full">
+        <label htmlFor={inlineFracStyleId}>Inline Frac Style:</label>
+        <select
+          id={inlineFracStyleId}
+          className="select"
+          value={formatOptions.inlineFracStyle}
+          onChange={(e) =>
+            setFormatOptions((prev) => ({
+              ...prev,
+              inlineFracStyle: e.target.value as FormatOptions["inlineFracStyle"],
+            }))
+          }
+        >
+          <option value="preserve">Preserve</option>
+          <option value="slash">Slash</option>
+          <option value="call">Call</option>
+        </select>
+      </div>
+
+      <div className="flex items-center justify-between w-full">
+        <label htmlFor={blockFracStyleId}>Block Frac Style:</label>
+        <select
+          id={blockFracStyleId}
+          className="select"
+          value={formatOptions.blockFracStyle}
+          onChange={(e) =>
+            setFormatOptions((prev) => ({
+              ...prev,
+              blockFracStyle: e.target.value as FormatOptions["blockFracStyle"],
+            }))
+          }
+        >
+          <option value="preserve">Preserve</option>
+          <option value="slash">Slash</option>
+          <option value="call">Call</option>
         </select>
       </div>
 
@@ -23,6 +23,15 @@ describe("WASM Binding Tests", () => {
     ).toBe("sentence");
   });
 
+  it("maps trailing comma policies to the WASM config", () => {
+    expect(
+      formatOptionsToConfig({
+        ...DEFAULT_FORMAT_OPTIONS,
+        trailingComma: "preserve",
+      }).trailing_comma,
+    ).toBe("preserve");
+  });
+
   // Test data
   const simpleTypstCode = `
 #set page(width: 10cm, height: auto)
@@ -110,6 +119,29 @@ The first #count numbers of the sequence are:
     });
   });
 
+  describe("Format With Warnings Function", () => {
+    it("should report skipped rewrites", () => {
+      const config = createConfig(2, 80);
+      const result = typstyle.format_with_warnings(
+        '#import "a.typ": y, x, y as x',
+        config,
+      ) as {
+        text: string;
+        warnings: { kind: string; reason: string; start: number; end: number }[];
+      };
+
+      expect(result.text).toBe('#import "a.typ": y, x, y as x\n');
+      expect(result.warnings).toEqual([
+        {
+          kind: "import-sorting",
+          reason: "the items bind the same name more than once",
+          start: 1,
+          end: 29,
+        },
+      ]);
+    });
+  });
+
   describe("Parse Function", () => {
     it("should parse simple Typst code and return AST", () => {
       const result = typstyle.parse(simpleTypstCode);
@@ -5,7 +5,26 @@ export interface FormatOptions {
   indentWidth: number;
   collapseMarkupSpaces: boolean;
   reorderImportItems: boolean;
+  reorderImports: boolean;
+  mergeImports: boolean;
   wrapMode: typstyle.Config["wrap_mode"];
+  trailingComma: typstyle.Config["trailing_comma"];
+  magicTrailingComma: boolean;
+  alignAssignments: boolean;
+  alignTrailingComments: boolean;
+  tableAlign: typstyle.Config["table_align"];
+  /** Comma-separated entries of `table_functions`. */
+  tableFunctions: string;
+  breakLongEquations: boolean;
+  mathSpacing: boolean;
+  symbolStyle: typstyle.Config["symbol_style"];
+  inlineFracStyle: typstyle.Config["inline_frac_style"];
+  blockFracStyle: typstyle.Config["block_frac_style"];
+  wrapAlgorithm: typstyle.Config["wrap_algorithm"];
+  cjkBreak: typstyle.Config["cjk_break"];
+  sentenceLocale: string;
+  /** Comma-separated entries of `sentence_abbreviations`. */
+  sentenceAbbreviations: string;
 }
 
 // Default format style options
@@ -14,7 +33,24 @@ export const DEFAULT_FORMAT_OPTIONS: FormatOptions = {
   indentWidth: 2,
   collapseMarkupSpaces: false,
   reorderImportItems: true,
+  reorderImports: false,
+  mergeImports: false,
   wrapMode: "none",
+  trailingComma: "always-when-multiline",
+  magicTrailingComma: false,
+  alignAssignments: false,
+  alignTrailingComments: false,
+  tableAlign: "none",
+  tableFunctions: "",
+  breakLongEquations: false,
+  mathSpacing: false,
+  symbolStyle: "preserve",
+  inlineFracStyle: "preserve",
+  blockFracStyle: "preserve",
+  wrapAlgorithm: "greedy",
+  cjkBreak: "never",
+  sentenceLocale: "en",
+  sentenceAbbreviations: "",
 };
 
 /**
@@ -43,6 +79,29 @@ export function formatOptionsToConfig(
     tab_spaces: options.indentWidth,
     collapse_markup_spaces: options.collapseMarkupSpaces,
     reorder_import_items: options.reorderImportItems,
+    reorder_imports: options.reorderImports,
+    merge_imports: options.mergeImports,
     wrap_mode: options.wrapMode,
+    trailing_comma: options.trailingComma,
+    magic_trailing_comma: options.magicTrailingComma,
+    align_assignments: options.alignAssignments,
+    align_trailing_comments: options.alignTrailingComments,
+    table_align: options.tableAlign,
+    table_functions: options.tableFunctions
+      .split(",")
+      .map((entry) => entry.trim())
+      .filter((entry) => entry !== ""),
+    break_long_equations: options.breakLongEquations,
+    math_spacing: options.mathSpacing,
+    symbol_style: options.symbolStyle,
+    inline_frac_style: options.inlineFracStyle,
+    block_frac_style: options.blockFracStyle,
+    wrap_algorithm: options.wrapAlgorithm,
+    cjk_break: options.cjkBreak,
+    sentence_locale: options.sentenceLocale,
+    sentence_abbreviations: options.sentenceAbbreviations
+      .split(",")
+      .map((entry) => entry.trim())
+      .filter((entry) => entry !== ""),
   };
 }
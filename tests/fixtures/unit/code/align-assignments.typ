@@ -0,0 +1,48 @@
+/// typstyle: align_assignments
+
+#let x = 1
+#let width = 10pt
+#let name = "a" // note
+#let y = 2
+
+#let z = 3
+
+#{
+  let a = 1
+  let bbb = 2 // note
+  let cc = 3
+  // own line
+  let dddd = 4
+  let e = 5
+}
+
+#rect(
+  fill: red,
+  stroke: blue,
+  inset: 4pt,
+)
+#rect(fill: red, stroke: blue)
+#let d = (
+  "a": 1,
+  long-key: 2,
+)
+
+#f(
+  a: 1,
+  bbb: 2,
+  x,
+  cc: 3,
+  d: 4,
+
+  eeeee: 5,
+)
+
+#let short = 1
+#let long-name = "a value that is long enough to overflow a line of forty"
+
+#let colors = (primary: red, secondary: blue, bg: white, accent: green)
+
+#f(
+  a: 1,
+  long-name: "a value of about thirty chars",
+)
@@ -0,0 +1,12 @@
+#import "a.typ": (
+  c, // about c
+  // about a
+  a,
+  b,
+)
+#import "a.typ": b, /* b or a? */ a
+#import "a.typ": (
+  b,
+  a,
+  // after all items
+)
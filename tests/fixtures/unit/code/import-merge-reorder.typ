@@ -0,0 +1,7 @@
+/// typstyle: merge_imports reorder_imports
+
+#import "a.typ": x
+#import "b.typ": y
+#import "a.typ": z
+
+Text
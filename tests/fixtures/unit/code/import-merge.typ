@@ -0,0 +1,22 @@
+/// typstyle: merge_imports
+
+#import "utils.typ": b
+#import "utils.typ": c, a, b
+#import "x.typ": x
+
+#import "a.typ": x
+#import "a.typ": *
+
+#import "a.typ": x
+#import "a.typ" as m: y
+
+#import "a.typ": x
+#import "a.typ": y as x
+
+#import "a.typ": x
+// comment
+#import "a.typ": y
+
+// @typstyle off
+#import "b.typ": y,  x
+#import "b.typ": z
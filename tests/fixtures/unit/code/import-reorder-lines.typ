@@ -0,0 +1,34 @@
+/// typstyle: reorder_imports
+
+#import "utils.typ": helper
+#import "@preview/cetz:0.10.0" as cetz-new
+#import "/template.typ": conf
+#import "@preview/cetz:0.4.0" as cetz-old
+#import "@preview/algo:0.3.0"
+
+#import "d.typ": d
+#import "c.typ": c
+
+#import "b.typ": b
+// comment
+#import "a.typ": a
+#import "0.typ": zero
+
+#import "b.typ": x
+#import "a.typ": x
+
+#import "b.typ"
+#import "a.typ": b
+
+#import "b.typ" as m
+#import m: x
+
+#import "d.typ": x
+#import "c.typ": *
+#import "b.typ": y
+#import "a.typ": z
+
+// @typstyle off
+#import "z.typ": a
+#import "b.typ": b
+#import "a.typ": c
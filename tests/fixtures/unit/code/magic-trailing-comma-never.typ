@@ -0,0 +1,3 @@
+/// typstyle: magic_trailing_comma trailing_comma=never
+
+#f(a, b,)
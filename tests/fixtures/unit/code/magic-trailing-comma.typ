@@ -0,0 +1,23 @@
+/// typstyle: magic_trailing_comma
+
+#f(a, b,)
+
+#let f(a, b,) = a
+
+#let a = (x: 1,)
+
+#f(a, b,)[c]
+
+#f(
+  a,
+  b
+)
+
+#let a = (
+  1,
+  2
+)
+
+#let a = (1,)
+
+#let a = (1, 2,)
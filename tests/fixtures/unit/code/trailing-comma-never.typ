@@ -0,0 +1,22 @@
+/// typstyle: trailing_comma=never
+
+#f(
+  a,
+  b,
+)
+
+#let (
+  a,
+  b,
+) = c
+
+#table(
+  columns: 2,
+  [a], [b],
+)
+
+#let a = (
+  1,
+)
+
+#let a = (1,)
@@ -0,0 +1,16 @@
+/// typstyle: trailing_comma=preserve
+
+#let a = (
+  1,
+  2
+)
+
+#let a = (
+  x: 1,
+  y: 2,
+)
+
+#let f(
+  a,
+  b, // b
+) = a
@@ -0,0 +1,21 @@
+/// typstyle: align_trailing_comments
+
+#let f(
+  a, // first
+  long-name, // second
+) = a
+
+#let x = 1 // one
+#let yyy = 2 // two
+// own line
+#let z = 3 // three
+#{
+  let a = 1 // four
+}
+
+#let a = 1 // short
+#let abcdefghij = 2 // a rather long comment
+
+Text// one
+More text // two
+Even more text // three
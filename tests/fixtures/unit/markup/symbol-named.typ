@@ -0,0 +1,8 @@
+/// typstyle: symbol_style=named
+
+1--2 and Dr.~Smith --- done...
+
+soft-?hyphen
+
+// @typstyle off
+--- kept
@@ -0,0 +1,15 @@
+/// typstyle: break_long_equations
+
+$ f(x) = a + b + c = d + e + f $
+
+$ E = m c^2 + (p c)^2 + k_1 x = m_0 c^2 + e $
+
+$ x = a_1 b_1 + a_2 b_2 + a_3 b_3 + a_4 b_4 + a_5 b_5 $
+
+$ f(x)
+  = a + b $
+
+$ f(x) = a + b + c \
+  = d + e + f $
+
+$f(x) = a + b + c = d + e + f$
@@ -0,0 +1,9 @@
+/// typstyle: inline_frac_style=slash block_frac_style=call
+
+$ (a + b)/c + x^2/2 $
+
+$ (a)/(b)(c) $
+
+$frac(a + b, c) = frac(x^2, (y)) + frac(1, frac(2, 3))$
+
+$2 frac(a, b)c$
@@ -0,0 +1,21 @@
+$ mat(
+  1, 22, 3;
+  444, 5, 66
+) $
+
+$ mat(delim: "[",
+  1, 2; 33, 4;
+  5, 6) $
+
+$ vec(1, 2,
+  3) $
+
+$ cases(
+  x "if" a,
+  y "else"
+) $
+
+$ mat(
+  "aaaaaaaaa", b, "ccccccccc";
+  d, "eeeeeeeee", f
+) $
@@ -0,0 +1,13 @@
+/// typstyle: math_spacing
+
+$a+b=c$
+
+$ x = -y+( z ) $
+
+$ f ' + a_(i=1) $
+
+$ a b+c d $
+
+$ (a, -b) + (c; -d) $
+
+$ x _ 2 + y ^ 3 $
@@ -0,0 +1,7 @@
+/// typstyle: symbol_style=named
+
+$a -> b != c$
+
+$a->b<=(c)$
+
+$a /* @typstyle off */ -> b -> c$
@@ -0,0 +1,8 @@
+/// typstyle: symbol_style=shorthand
+
+#let arrow = 1
+#import "lt.typ"
+
+$a arrow.r b sym.eq.not c gt.eq d lt.eq e$
+
+1--2 and Dr.~Smith #sym.dash.en
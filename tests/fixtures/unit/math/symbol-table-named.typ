@@ -0,0 +1,35 @@
+/// typstyle: symbol_style=named
+// Every math shorthand with a name, checked against Typst by the consistency tests.
+
+$a -> b$
+$a |-> b$
+$a => b$
+$a |=> b$
+$a ==> b$
+$a --> b$
+$a ~~> b$
+$a ~> b$
+$a >-> b$
+$a ->> b$
+$a <- b$
+$a <== b$
+$a <-- b$
+$a <~~ b$
+$a <~ b$
+$a <-< b$
+$a <<- b$
+$a <-> b$
+$a <=> b$
+$a <==> b$
+$a <--> b$
+$a := b$
+$a ::= b$
+$a ... b$
+$a =: b$
+$a != b$
+$a >> b$
+$a >= b$
+$a >>> b$
+$a << b$
+$a <= b$
+$a <<< b$
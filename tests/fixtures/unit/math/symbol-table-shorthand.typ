@@ -0,0 +1,35 @@
+/// typstyle: symbol_style=shorthand
+// Every symbol name with a math shorthand, checked against Typst by the consistency tests.
+
+$a arrow.r b$
+$a arrow.r.bar b$
+$a arrow.r.double b$
+$a arrow.r.double.bar b$
+$a arrow.r.double.long b$
+$a arrow.r.long b$
+$a arrow.r.long.squiggly b$
+$a arrow.r.squiggly b$
+$a arrow.r.tail b$
+$a arrow.r.twohead b$
+$a arrow.l b$
+$a arrow.l.double.long b$
+$a arrow.l.long b$
+$a arrow.l.long.squiggly b$
+$a arrow.l.squiggly b$
+$a arrow.l.tail b$
+$a arrow.l.twohead b$
+$a arrow.l.r b$
+$a arrow.l.r.double b$
+$a arrow.l.r.double.long b$
+$a arrow.l.r.long b$
+$a colon.eq b$
+$a colon.double.eq b$
+$a dots.h b$
+$a eq.colon b$
+$a eq.not b$
+$a gt.double b$
+$a gt.eq b$
+$a gt.triple b$
+$a lt.double b$
+$a lt.eq b$
+$a lt.triple b$
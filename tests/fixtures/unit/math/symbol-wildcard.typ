@@ -0,0 +1,5 @@
+/// typstyle: symbol_style=named
+
+#import "defs.typ": *
+
+$a -> b$ -- text
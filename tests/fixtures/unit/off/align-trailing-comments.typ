@@ -0,0 +1,16 @@
+/// typstyle: align_trailing_comments
+
+#let x = 1 // one
+#let yyy = 2 // two
+
+// @typstyle off
+#let f(
+  a, // first
+  long-name, // second
+) = a
+
+// @typstyle off
+#{
+  let a = 1 // three
+  let bbb = 2 // four
+}
@@ -0,0 +1,7 @@
+/// typstyle: table_align=center
+
+#table(
+  columns: 3,
+  [a], [bbb], [1],
+  [cccc], [d], [100],
+)
@@ -0,0 +1,16 @@
+/// typstyle: table_align=left
+
+#table(
+  columns: 3,
+  [a], [bbb], [1],
+  [cccc], [d], [100],
+)
+
+#table(
+  columns: 2,
+  [a], [b],
+  [cc], {
+    1
+    2
+  },
+)
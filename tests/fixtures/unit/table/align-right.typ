@@ -0,0 +1,13 @@
+/// typstyle: table_align=right
+
+#table(
+  columns: 3,
+  [a], [bbb], [1],
+  [cccc], [d], [100],
+)
+
+#table(
+  columns: 2,
+  [a], [b],
+  [a rather long cell with many words], [another long cell],
+)
@@ -0,0 +1,6 @@
+#let n = 2
+#let cols = (1fr, 1fr)
+#let huge = 100000
+#table(columns: n, [a], [b], [c], [d])
+#table(columns: cols, [a], [b], [c], [d])
+#table(columns: huge, [a], [b], [c], [d])
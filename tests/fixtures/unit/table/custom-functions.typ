@@ -0,0 +1,20 @@
+/// typstyle: table_functions=my-table,tablex.tablex:cols
+
+#my-table(columns: 2, [a], [b], [c], [d])
+
+#tablex.tablex(cols: 2, [a], [b], [c], [d])
+
+#other(columns: 2, [a], [b], [c], [d])
+
+#my-table(
+  columns: 2,
+  [a], [b],
+  hlinex(stroke: red),
+  [c], [d],
+)
+
+#tablex.tablex(
+  cols: 2,
+  colspanx(2)[a],
+  [b], [c],
+)
@@ -1,7 +1,9 @@
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result, bail};
-use typstyle_core::WrapMode;
+use typstyle_core::{
+    CjkBreak, FracStyle, SymbolStyle, TableAlign, TrailingComma, WrapAlgorithm, WrapMode,
+};
 
 use super::{Options, read_content};
 
@@ -65,14 +67,107 @@ pub fn parse_directives(content: &str) -> Result<Options> {
             "reorder_import_items" => {
                 config.reorder_import_items = value != Some("false");
             }
+            "reorder_imports" => {
+                config.reorder_imports = value != Some("false");
+            }
+            "merge_imports" => {
+                config.merge_imports = value != Some("false");
+            }
             "wrap_text" => {
                 config.wrap_mode = match value {
                     Some("false") | Some("none") => WrapMode::None,
                     Some("sentence") => WrapMode::Sentence,
+                    Some("clause") => WrapMode::Clause,
                     _ => WrapMode::Fill,
                 };
                 config.collapse_markup_spaces |= config.wrap_mode != WrapMode::None;
             }
+            "trailing_comma" => {
+                config.trailing_comma = match value {
+                    Some("always-when-multiline") => TrailingComma::AlwaysWhenMultiline,
+                    Some("never") => TrailingComma::Never,
+                    Some("preserve") => TrailingComma::Preserve,
+                    _ => bail!("Invalid trailing_comma value: {value:?}"),
+                };
+            }
+            "magic_trailing_comma" => {
+                config.magic_trailing_comma = value != Some("false");
+            }
+            "align_assignments" => {
+                config.align_assignments = value != Some("false");
+            }
+            "align_trailing_comments" => {
+                config.align_trailing_comments = value != Some("false");
+            }
+            "table_align" => {
+                config.table_align = match value {
+                    Some("none") => TableAlign::None,
+                    Some("left") => TableAlign::Left,
+                    Some("right") => TableAlign::Right,
+                    Some("center") => TableAlign::Center,
+                    _ => bail!("Invalid table_align value: {value:?}"),
+                };
+            }
+            "table_functions" => {
+                let Some(value) = value else {
+                    bail!("table_functions directive requires a value");
+                };
+                config.table_functions = value.split(',').map(|s| s.trim().to_string()).collect();
+            }
+            "break_long_equations" => {
+                config.break_long_equations = value != Some("false");
+            }
+            "math_spacing" => {
+                config.math_spacing = value != Some("false");
+            }
+            "symbol_style" => {
+                config.symbol_style = match value {
+                    Some("preserve") => SymbolStyle::Preserve,
+                    Some("shorthand") => SymbolStyle::Shorthand,
+                    Some("named") => SymbolStyle::Named,
+                    _ => bail!("Invalid symbol_style value: {value:?}"),
+                };
+            }
+            "inline_frac_style" | "block_frac_style" => {
+                let style = match value {
+                    Some("preserve") => FracStyle::Preserve,
+                    Some("slash") => FracStyle::Slash,
+                    Some("call") => FracStyle::Call,
+                    _ => bail!("Invalid {key} value: {value:?}"),
+                };
+                if key == "inline_frac_style" {
+                    config.inline_frac_style = style;
+                } else {
+                    config.block_frac_style = style;
+                }
+            }
+            "wrap_algorithm" => {
+                config.wrap_algorithm = match value {
+                    Some("greedy") => WrapAlgorithm::Greedy,
+                    Some("optimal") => WrapAlgorithm::Optimal,
+                    _ => bail!("Invalid wrap_algorithm value: {value:?}"),
+                };
+            }
+            "cjk_break" => {
+                config.cjk_break = match value {
+                    Some("never") => CjkBreak::Never,
+                    Some("allow") => CjkBreak::Allow,
+                    _ => bail!("Invalid cjk_break value: {value:?}"),
+                };
+            }
+            "sentence_locale" => {
+                let Some(value) = value else {
+                    bail!("sentence_locale directive requires a value");
+                };
+                config.sentence_locale = value.to_string();
+            }
+            "sentence_abbreviations" => {
+                let Some(value) = value else {
+                    bail!("sentence_abbreviations directive requires a value");
+                };
+                config.sentence_abbreviations =
+                    value.split(',').map(|s| s.trim().to_string()).collect();
+            }
             "collapse_markup_spaces" => {
                 config.collapse_markup_spaces = value != Some("false");
             }
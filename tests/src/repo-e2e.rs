@@ -159,6 +159,7 @@ fn check_testcase(
                 make_formatter(config.config.clone()),
                 &mut sub_sink,
             )?,
+            config: format!("{:#?}", config.config),
         });
         sub_sink.sink_to(&mut err_sink);
     }
@@ -184,6 +184,7 @@ fn check_output_consistency(path: &Path, width: usize) -> Result<(), Failed> {
     }
 
     cfg.max_width = width;
+    let config = format!("{cfg:#?}");
     let t = Typstyle::new(cfg);
 
     let mut err_sink = ErrorSink::new(format!("consistency {}", path.display()));
@@ -191,6 +192,10 @@ fn check_output_consistency(path: &Path, width: usize) -> Result<(), Failed> {
     let mut harness = FormatterHarness::new("".to_string(), PathBuf::new())?;
     let main_vpath = path.strip_prefix(fixtures_dir())?;
     harness.add_source_file(main_vpath, source.text())?;
+    let report_dir = env::var_os("TYPSTYLE_HTML_REPORT").map(PathBuf::from);
+    if report_dir.is_some() {
+        harness.enable_html_report();
+    }
 
     let base_world = harness.snapshot();
     let fmt_sources = FormattedSources {
@@ -200,6 +205,7 @@ fn check_output_consistency(path: &Path, width: usize) -> Result<(), Failed> {
             |source| Ok(t.format_source(source).render()?),
             &mut err_sink,
         )?,
+        config,
     };
 
     harness.compile_and_compare(
@@ -209,6 +215,13 @@ fn check_output_consistency(path: &Path, width: usize) -> Result<(), Failed> {
         &mut err_sink,
     )?;
 
+    if let (Some(dir), Some(report)) = (report_dir, harness.html_report())
+        && !report.is_empty()
+    {
+        let name = main_vpath.display().to_string().replace(['/', '\\'], "__");
+        report.write(&dir.join(format!("{name}-{width}.html")))?;
+    }
+
     if err_sink.is_ok() {
         Ok(())
     } else {